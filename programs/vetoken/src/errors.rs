@@ -32,4 +32,26 @@ pub enum CustomError {
     InvalidLockup,
     #[msg("Invalid Vote Record")]
     InvalidVoteRecord,
+    #[msg("Early Unlock Disabled")]
+    EarlyUnlockDisabled,
+    #[msg("Lockup Has Active Votes")]
+    LockupHasActiveVotes,
+    #[msg("Lockup Delegated")]
+    LockupDelegated,
+    #[msg("Invalid Delegation")]
+    InvalidDelegation,
+    #[msg("Unstake Cooldown Not Ended")]
+    UnstakeCooldownNotEnded,
+    #[msg("Emergency Unlocked")]
+    EmergencyUnlocked,
+    #[msg("Lockup Vesting")]
+    LockupVesting,
+    #[msg("Lockup Without End Ts")]
+    LockupWithoutEndTs,
+    #[msg("Lockup Has Receipt")]
+    LockupHasReceipt,
+    #[msg("Invalid Receipt")]
+    InvalidReceipt,
+    #[msg("Invalid Choice")]
+    InvalidChoice,
 }
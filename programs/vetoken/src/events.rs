@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct LockupMultipliersUpdated {
+    pub ns: Pubkey,
+    pub lockup: Pubkey,
+    pub security_council: Pubkey,
+    pub old_target_voting_pct: u16,
+    pub new_target_voting_pct: u16,
+    pub old_target_rewards_pct: u16,
+    pub new_target_rewards_pct: u16,
+    pub ts: i64,
+}
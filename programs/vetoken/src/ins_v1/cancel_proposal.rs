@@ -0,0 +1,30 @@
+use crate::{
+    errors::CustomError,
+    states::{Namespace, Proposal, PROPOSAL_STATUS_CANCELLED},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account()]
+    review_council: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=ns,
+      constraint = ns.now() < proposal.start_ts @ CustomError::InvalidTimestamp,
+      constraint = !proposal.is_finalized() @ CustomError::InvalidProposalState,
+    )]
+    proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+      has_one=review_council,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CancelProposal<'info>>) -> Result<()> {
+    ctx.accounts.proposal.status = PROPOSAL_STATUS_CANCELLED;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::{
+    errors::CustomError,
+    ins_v1::vote::count_other_lockups,
+    states::{Lockup, Namespace, Proposal, VoteRecordV2, MAX_VOTE_LOCKUPS, PROPOSAL_STATUS_ACTIVE},
+};
+use anchor_lang::prelude::*;
+use std::cmp::max;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ChangeVoteArgs {
+    choice: u8,
+    choice_weights_pct: Vec<u8>, // optional split of the voting power over the choices, must sum up to 100 with the largest weight at choice
+}
+
+#[derive(Accounts)]
+#[instruction(args:ChangeVoteArgs)]
+pub struct ChangeVote<'info> {
+    #[account()]
+    owner: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=ns,
+      constraint = ns.now() >= proposal.start_ts && ns.now() <= proposal.end_ts @ CustomError::InvalidTimestamp,
+      constraint = proposal.current_status(&ns) == PROPOSAL_STATUS_ACTIVE @ CustomError::InvalidProposalState,
+      constraint = proposal.valid_choice(args.choice, &args.choice_weights_pct) @ CustomError::InvalidChoice,
+    )]
+    proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+      mut,
+      seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+      has_one=owner,
+      has_one=ns,
+      constraint = !lockup.delegated @ CustomError::LockupDelegated,
+      constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+      constraint = lockup.voting_power_at(&ns, proposal.start_ts) > 0 @ CustomError::InvalidVotingPower,
+      constraint = lockup.effective_end_ts(&ns) > proposal.end_ts @ CustomError::InvalidTimestamp,
+      bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    // only the owner's own votes, delegated and receipt votes are changed by revoking
+    // them with revoke_vote and voting again
+    #[account(
+      mut,
+      seeds=[b"vote_record", ns.key().as_ref(), owner.key().as_ref(), proposal.key().as_ref()],
+      has_one=ns,
+      has_one=owner,
+      has_one=proposal,
+      constraint = vote_record.delegate == Pubkey::default() @ CustomError::InvalidVoteRecord,
+      bump,
+    )]
+    vote_record: Box<Account<'info, VoteRecordV2>>,
+
+    #[account(
+        constraint = !ns.emergency_unlock @ CustomError::EmergencyUnlocked,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+// The owner's other lockups are passed as remaining accounts like in Vote, the old
+// voting power is taken off the old choice and the current one goes to the new choice.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ChangeVote<'info>>,
+    args: ChangeVoteArgs,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &ctx.accounts.ns;
+    let vote_record = &mut ctx.accounts.vote_record;
+
+    proposal.revoke_split_vote(
+        &vote_record.cast_choice_weights_pct(),
+        vote_record.voting_power,
+    )?;
+
+    let mut voting_power = lockup.voting_power_at(ns, proposal.start_ts);
+    let mut lockup_keys = vec![lockup.key()];
+    voting_power = voting_power
+        .checked_add(count_other_lockups(
+            ns,
+            proposal,
+            &ctx.accounts.owner.key(),
+            &mut lockup_keys,
+            ctx.remaining_accounts,
+            ctx.program_id,
+        )?)
+        .expect("should not overflow");
+
+    lockup.voted_proposal_end_ts = max(lockup.voted_proposal_end_ts, proposal.end_ts);
+
+    vote_record.set_choice_weights(args.choice, &args.choice_weights_pct);
+    vote_record.voting_power = voting_power;
+    vote_record.lockup = lockup.key();
+    vote_record.lockups = [Pubkey::default(); MAX_VOTE_LOCKUPS];
+    vote_record.lockups[..lockup_keys.len()].copy_from_slice(&lockup_keys);
+
+    if !vote_record.valid() {
+        return Err(CustomError::InvalidVoteRecord.into());
+    }
+
+    proposal.cast_split_vote(&vote_record.choice_weights_pct, voting_power)?;
+
+    Ok(())
+}
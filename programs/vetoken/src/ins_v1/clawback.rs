@@ -0,0 +1,141 @@
+use crate::{
+    errors::CustomError,
+    lockup_seeds,
+    states::{Delegation, Lockup, Namespace},
+};
+use anchor_lang::{prelude::*, AccountsClose};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+// Clawback means that security_council takes back the unvested tokens of a vesting lockup.
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    #[account(mut)]
+    security_council: Signer<'info>,
+
+    /// CHECK: owner of the vesting lockup, checked by has_one
+    #[account(mut)]
+    owner: UncheckedAccount<'info>,
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = security_council,
+        payer = security_council,
+    )]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = lockup.unvested_amount(&ns) > 0 @ CustomError::InvalidLockupAmount,
+        constraint = !lockup.delegated || delegation.is_some() @ CustomError::LockupDelegated,
+        constraint = ns.lockup_amount >= lockup.amount @ CustomError::Overflow,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = lockup,
+        constraint = lockup_token_account.amount >= lockup.amount @ CustomError::InvalidLockupAmount,
+    )]
+    lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // a delegated lockup closes its delegation when the clawback closes the lockup,
+    // otherwise the delegation could never be revoked again
+    #[account(
+        mut,
+        seeds=[b"delegation", ns.key().as_ref(), lockup.key().as_ref()],
+        has_one=ns,
+        has_one=owner,
+        has_one=lockup,
+        bump,
+    )]
+    delegation: Option<Box<Account<'info, Delegation>>>,
+
+    #[account(
+        mut,
+        has_one = token_mint,
+        has_one = security_council,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Clawback<'info>>) -> Result<()> {
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &mut ctx.accounts.ns;
+    let owner = &ctx.accounts.owner;
+    let index = lockup.index;
+    let bump = ctx.bumps.lockup;
+    let amount = lockup.clawback_amount(ns);
+
+    lockup.seed_checkpoint();
+
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                from: ctx.accounts.lockup_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: lockup.to_account_info(),
+            },
+            &[lockup_seeds!(ns, owner, index, bump)],
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    lockup.amount = lockup
+        .amount
+        .checked_sub(amount)
+        .expect("underflow in reducing lockup.amount");
+
+    ns.lockup_amount = ns
+        .lockup_amount
+        .checked_sub(amount)
+        .expect("underflow in reducing ns.lockup_amount");
+
+    // the vesting stops here, whatever has vested so far is fully owned by the owner. The
+    // schedule is kept, the checkpoints before the clawback still vest along it.
+    lockup.vesting_amount = 0;
+
+    lockup.write_checkpoint(ns);
+
+    if lockup.amount == 0 {
+        if ctx.accounts.lockup_token_account.amount == amount {
+            anchor_spl::token_interface::close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::CloseAccount {
+                    account: ctx.accounts.lockup_token_account.to_account_info(),
+                    destination: owner.to_account_info(),
+                    authority: lockup.to_account_info(),
+                },
+                &[lockup_seeds!(ns, owner, index, bump)],
+            ))?;
+        }
+
+        if let Some(delegation) = &ctx.accounts.delegation {
+            delegation.close(owner.to_account_info())?;
+        }
+        lockup.close(owner.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::{
+    errors::CustomError,
+    states::{Delegation, Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct DelegateLockup<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    /// CHECK: delegate is an input parameter, it will be able to vote with the lockup's voting power
+    #[account()]
+    delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = !lockup.has_active_votes(&ns) @ CustomError::LockupHasActiveVotes,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        init_if_needed, // owners can re-delegate to another delegate
+        payer=owner,
+        seeds=[b"delegation", ns.key().as_ref(), lockup.key().as_ref()],
+        space=8 + Delegation::INIT_SPACE,
+        bump,
+    )]
+    delegation: Box<Account<'info, Delegation>>,
+
+    #[account()]
+    ns: Box<Account<'info, Namespace>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, DelegateLockup<'info>>) -> Result<()> {
+    let delegation = &mut ctx.accounts.delegation;
+    let lockup = &mut ctx.accounts.lockup;
+
+    delegation.ns = ctx.accounts.ns.key();
+    delegation.lockup = lockup.key();
+    delegation.owner = ctx.accounts.owner.key();
+    delegation.delegate = ctx.accounts.delegate.key();
+
+    lockup.delegated = true;
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use crate::{
+    errors::CustomError,
+    lockup_seeds,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct EarlyUnstake<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = owner,
+        payer = owner,
+    )]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.delegated @ CustomError::LockupDelegated,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = lockup.unvested_amount(&ns) == 0 @ CustomError::LockupVesting,
+        constraint = lockup.effective_end_ts(&ns) > ns.now() @ CustomError::InvalidTimestamp, // use unstake for expired lockups
+        constraint = !lockup.has_active_votes(&ns) @ CustomError::LockupHasActiveVotes,
+        constraint = ns.lockup_amount >= lockup.amount @ CustomError::Overflow,
+        bump,
+        close=owner,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = lockup,
+        constraint = lockup_token_account.amount >= lockup.amount @ CustomError::InvalidLockupAmount,
+    )]
+    lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // penalty vault is owned by the namespace, and only the security council can withdraw from it
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = ns,
+        payer = owner,
+    )]
+    penalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = token_mint,
+        constraint = ns.lockup_early_unlock_penalty_pct > 0 @ CustomError::EarlyUnlockDisabled,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, EarlyUnstake<'info>>) -> Result<()> {
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &mut ctx.accounts.ns;
+    let amount = lockup.amount;
+    let penalty = lockup.early_unlock_penalty(ns);
+    let owner = &ctx.accounts.owner;
+    let index = lockup.index;
+    let bump = ctx.bumps.lockup;
+
+    if penalty > 0 {
+        anchor_spl::token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::TransferChecked {
+                    from: ctx.accounts.lockup_token_account.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.penalty_token_account.to_account_info(),
+                    authority: lockup.to_account_info(),
+                },
+                &[lockup_seeds!(ns, owner, index, bump)],
+            ),
+            penalty,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                from: ctx.accounts.lockup_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: lockup.to_account_info(),
+            },
+            &[lockup_seeds!(ns, owner, index, bump)],
+        ),
+        amount
+            .checked_sub(penalty)
+            .expect("penalty should not exceed the lockup amount"),
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    if ctx.accounts.lockup_token_account.amount == amount {
+        anchor_spl::token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::CloseAccount {
+                account: ctx.accounts.lockup_token_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: lockup.to_account_info(),
+            },
+            &[lockup_seeds!(ns, owner, index, bump)],
+        ))?;
+    }
+
+    lockup.amount = 0;
+
+    ns.lockup_amount = ns
+        .lockup_amount
+        .checked_sub(amount)
+        .expect("underflow in reducing ns.lockup_amount");
+
+    Ok(())
+}
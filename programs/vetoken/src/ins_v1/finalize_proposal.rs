@@ -0,0 +1,26 @@
+use crate::{
+    errors::CustomError,
+    states::{Namespace, Proposal},
+};
+use anchor_lang::prelude::*;
+
+// FinalizeProposal means that anyone can write the result of an ended proposal.
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(
+      mut,
+      has_one=ns,
+      constraint = ns.now() > proposal.end_ts @ CustomError::InvalidTimestamp,
+      constraint = !proposal.is_finalized() @ CustomError::InvalidProposalState,
+    )]
+    proposal: Box<Account<'info, Proposal>>,
+
+    #[account()]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeProposal<'info>>) -> Result<()> {
+    ctx.accounts.proposal.finalize(&ctx.accounts.ns);
+
+    Ok(())
+}
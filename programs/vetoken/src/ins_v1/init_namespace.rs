@@ -1,4 +1,7 @@
-use crate::{errors::CustomError, states::Namespace};
+use crate::{
+    errors::CustomError,
+    states::{Namespace, VOTING_POWER_MODE_LOCKUP_DURATION},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     token_2022::{
@@ -80,6 +83,11 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, InitNamespace<'info>>) -> R
     ns.proposal_min_voting_power_for_quorum = 10 * 1_000_000; // minimum participation voting power, please change this
     ns.proposal_min_pass_pct = 60; // 60%, the population is total_votes
     ns.proposal_can_update_after_votes = false;
+    ns.lockup_early_unlock_penalty_pct = 0; // early unstake is disabled by default
+    ns.lockup_voting_power_mode = VOTING_POWER_MODE_LOCKUP_DURATION;
+    ns.lockup_unstake_cooldown = 0; // unstake right after end_ts by default
+    ns.emergency_unlock = false;
+    ns.lockup_voting_curve_len = 0; // linear voting power ramp by default
 
     if !ns.valid() {
         return Err(CustomError::InvalidNamespace.into());
@@ -1,6 +1,6 @@
 use crate::{
     errors::CustomError,
-    states::{Namespace, Proposal},
+    states::{Namespace, Proposal, PROPOSAL_STATUS_DRAFT},
 };
 use anchor_lang::prelude::*;
 
@@ -9,6 +9,8 @@ pub struct InitProposalArgs {
     start_ts: i64,
     end_ts: i64,
     uri: String,
+    num_choices: u8,
+    choice_labels: Vec<String>, // optional short labels of the choices, e.g. ["Yes", "No"]
 }
 
 #[derive(Accounts)]
@@ -23,6 +25,7 @@ pub struct InitProposal<'info> {
       payer=review_council,
       space=8+Proposal::INIT_SPACE,
       constraint = args.end_ts >= args.start_ts @ CustomError::InvalidTimestamp,
+      constraint = args.num_choices > 0 @ CustomError::InvalidChoice,
       bump,
     )]
     proposal: Box<Account<'info, Proposal>>,
@@ -49,11 +52,19 @@ pub fn handle<'info>(
     proposal.end_ts = args.end_ts;
     proposal.owner = ctx.accounts.review_council.key();
     proposal.nonce = ns.proposal_nonce;
+    proposal.status = PROPOSAL_STATUS_DRAFT;
+    proposal.num_choices = args.num_choices;
+    proposal.min_voting_power_for_quorum = ns.proposal_min_voting_power_for_quorum;
+    proposal.min_pass_pct = ns.proposal_min_pass_pct;
 
     if !proposal.valid() {
         return Err(CustomError::InvalidProposalState.into());
     }
 
+    if !proposal.set_choice_labels(&args.choice_labels) {
+        return Err(CustomError::InvalidChoice.into());
+    }
+
     ns.proposal_nonce = ns
         .proposal_nonce
         .checked_add(1)
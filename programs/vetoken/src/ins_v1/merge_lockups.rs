@@ -0,0 +1,131 @@
+use crate::{
+    errors::CustomError,
+    lockup_seeds,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct MergeLockups<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // destination lockup
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.delegated @ CustomError::LockupDelegated,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = lockup.unvested_amount(&ns) == 0 @ CustomError::LockupVesting,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = lockup,
+    )]
+    lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(source_lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !source_lockup.delegated @ CustomError::LockupDelegated,
+        constraint = !source_lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = source_lockup.unvested_amount(&ns) == 0 @ CustomError::LockupVesting,
+        constraint = source_lockup.key() != lockup.key() @ CustomError::InvalidLockup,
+        constraint = source_lockup.target_voting_pct == lockup.target_voting_pct @ CustomError::InvalidLockup,
+        constraint = source_lockup.target_rewards_pct == lockup.target_rewards_pct @ CustomError::InvalidLockup,
+        bump,
+        close=owner,
+    )]
+    source_lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = source_lockup,
+        constraint = source_lockup_token_account.amount >= source_lockup.amount @ CustomError::InvalidLockupAmount,
+    )]
+    source_lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        has_one = token_mint,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, MergeLockups<'info>>) -> Result<()> {
+    // Get the data length before creating the mutable borrow
+    let data_len = ctx.accounts.lockup.to_account_info().data_len();
+    let source_data_len = ctx.accounts.source_lockup.to_account_info().data_len();
+    let lockup = &mut ctx.accounts.lockup;
+    let source_lockup = &mut ctx.accounts.source_lockup;
+    let ns = &ctx.accounts.ns;
+    let amount = source_lockup.amount;
+    let owner = &ctx.accounts.owner;
+    let source_index = source_lockup.index;
+    let source_bump = ctx.bumps.source_lockup;
+
+    lockup.normalize_weighted_start_ts(data_len);
+    source_lockup.normalize_weighted_start_ts(source_data_len);
+    lockup.seed_checkpoint();
+
+    if amount > 0 {
+        anchor_spl::token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::TransferChecked {
+                    from: ctx.accounts.source_lockup_token_account.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.lockup_token_account.to_account_info(),
+                    authority: source_lockup.to_account_info(),
+                },
+                &[lockup_seeds!(ns, owner, source_index, source_bump)],
+            ),
+            amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    if ctx.accounts.source_lockup_token_account.amount == amount {
+        anchor_spl::token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::CloseAccount {
+                account: ctx.accounts.source_lockup_token_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: source_lockup.to_account_info(),
+            },
+            &[lockup_seeds!(ns, owner, source_index, source_bump)],
+        ))?;
+    }
+
+    lockup.merge(ns, source_lockup)?;
+    source_lockup.amount = 0;
+
+    lockup.write_checkpoint(ns);
+
+    if !lockup.valid(ns) {
+        return Err(CustomError::InvalidLockup.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,406 @@
+use crate::{
+    errors::CustomError,
+    receipt_authority_seeds,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::{invoke, invoke_signed},
+    system_program,
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{
+        spl_token_2022::{
+            self,
+            extension::{metadata_pointer, ExtensionType},
+            instruction::AuthorityType,
+        },
+        Token2022,
+    },
+    token_interface::TokenAccount,
+};
+use spl_token_metadata_interface::state::Field;
+
+pub const RECEIPT_NAME: &str = "veToken Lockup";
+pub const RECEIPT_SYMBOL: &str = "VELOCK";
+pub const RECEIPT_URI: &str = "";
+
+// The receipt authority can close the mint once the receipt is burned, so the lockup PDA can get a
+// new receipt after being unstaked and staked again
+pub const RECEIPT_MINT_EXTENSIONS: [ExtensionType; 2] = [
+    ExtensionType::MetadataPointer,
+    ExtensionType::MintCloseAuthority,
+];
+
+// The position details carried in the receipt's token metadata
+pub fn receipt_metadata_fields(lockup: &Lockup) -> Vec<(String, String)> {
+    vec![
+        ("amount".to_string(), lockup.amount.to_string()),
+        ("end_ts".to_string(), lockup.end_ts.to_string()),
+        (
+            "target_voting_pct".to_string(),
+            lockup.target_voting_pct.to_string(),
+        ),
+    ]
+}
+
+// Size of the mint account with the fixed size extensions and the token metadata extension
+pub fn receipt_mint_len(fields: &[(String, String)]) -> Result<usize> {
+    let mint_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+        &RECEIPT_MINT_EXTENSIONS,
+    )?;
+    let metadata_len = 8 // type discriminator
+        + 4 // length
+        + 32 // update_authority
+        + 32 // mint
+        + 4 + RECEIPT_NAME.len()
+        + 4 + RECEIPT_SYMBOL.len()
+        + 4 + RECEIPT_URI.len()
+        + 4 + fields.iter().map(|(k, v)| 4 + k.len() + 4 + v.len()).sum::<usize>();
+    Ok(mint_len + metadata_len)
+}
+
+// The holder of the receipt acts for the lockup in vote_with_receipt, request_unstake_with_receipt
+// and unstake_with_receipt
+pub fn is_receipt_holder(
+    lockup: &Lockup,
+    receipt_token_account: &TokenAccount,
+    holder: &Pubkey,
+) -> bool {
+    lockup.has_receipt()
+        && receipt_token_account.mint == lockup.receipt_mint
+        && receipt_token_account.owner == *holder
+        && receipt_token_account.amount == 1
+}
+
+pub fn update_receipt_metadata<'info>(
+    token_program: &AccountInfo<'info>,
+    receipt_mint: &AccountInfo<'info>,
+    receipt_authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    fields: Vec<(String, String)>,
+) -> Result<()> {
+    for (key, value) in fields {
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::update_field(
+                token_program.key,
+                receipt_mint.key,
+                receipt_authority.key,
+                Field::Key(key),
+                value,
+            ),
+            &[receipt_mint.clone(), receipt_authority.clone()],
+            signer_seeds,
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MintLockupReceipt<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = !lockup.delegated @ CustomError::LockupDelegated,
+        constraint = lockup.unvested_amount(&ns) == 0 @ CustomError::LockupVesting,
+        constraint = !lockup.perpetual @ CustomError::InvalidLockup, // the holder cannot turn it off, so it could never be redeemed
+        constraint = !lockup.has_active_votes(&ns) @ CustomError::LockupHasActiveVotes, // the holder votes with a different vote record
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    /// CHECK: the receipt mint is created in the handler with the token metadata extensions
+    #[account(
+        mut,
+        seeds=[b"lockup_receipt", lockup.key().as_ref()],
+        bump,
+    )]
+    receipt_mint: UncheckedAccount<'info>,
+
+    /// CHECK: PDA of the namespace, the mint authority and the metadata update authority of the receipts
+    #[account(
+        seeds=[b"receipt_authority", ns.key().as_ref()],
+        bump,
+    )]
+    receipt_authority: UncheckedAccount<'info>,
+
+    /// CHECK: the owner's receipt token account, created in the handler by the associated token program
+    #[account(mut)]
+    receipt_token_account: UncheckedAccount<'info>,
+
+    #[account()]
+    ns: Box<Account<'info, Namespace>>,
+
+    receipt_token_program: Program<'info, Token2022>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, MintLockupReceipt<'info>>) -> Result<()> {
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &ctx.accounts.ns;
+    let receipt_mint = ctx.accounts.receipt_mint.to_account_info();
+    let receipt_authority = ctx.accounts.receipt_authority.to_account_info();
+    let token_program = ctx.accounts.receipt_token_program.to_account_info();
+    let lockup_key = lockup.key();
+    let ns_key = ns.key();
+    let authority_bump = ctx.bumps.receipt_authority;
+    let authority_seeds: &[&[&[u8]]] = &[receipt_authority_seeds!(ns_key, authority_bump)];
+
+    // The mint is allocated for the fixed size extensions only, and token-2022 reallocs it when
+    // the metadata is added, so the rent is paid upfront for the full size.
+    let fields = receipt_metadata_fields(lockup);
+    let mint_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+        &RECEIPT_MINT_EXTENSIONS,
+    )?;
+    let rent = Rent::get()?.minimum_balance(receipt_mint_len(&fields)?);
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let mint_seeds: &[&[&[u8]]] = &[&[
+        b"lockup_receipt",
+        lockup_key.as_ref(),
+        &[ctx.bumps.receipt_mint],
+    ]];
+
+    // Anyone can send lamports to the mint address beforehand, which makes create_account fail,
+    // so such an account is topped up, allocated and assigned instead, same as Anchor's init
+    if receipt_mint.lamports() == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::CreateAccount {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: receipt_mint.clone(),
+                },
+                mint_seeds,
+            ),
+            rent,
+            mint_len as u64,
+            &Token2022::id(),
+        )?;
+    } else {
+        let missing = rent.saturating_sub(receipt_mint.lamports());
+        if missing > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: receipt_mint.clone(),
+                    },
+                ),
+                missing,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: receipt_mint.clone(),
+                },
+                mint_seeds,
+            ),
+            mint_len as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::Assign {
+                    account_to_assign: receipt_mint.clone(),
+                },
+                mint_seeds,
+            ),
+            &Token2022::id(),
+        )?;
+    }
+
+    invoke(
+        &metadata_pointer::instruction::initialize(
+            &Token2022::id(),
+            receipt_mint.key,
+            Some(receipt_authority.key()),
+            Some(receipt_mint.key()),
+        )?,
+        std::slice::from_ref(&receipt_mint),
+    )?;
+    invoke(
+        &spl_token_2022::instruction::initialize_mint_close_authority(
+            &Token2022::id(),
+            receipt_mint.key,
+            Some(receipt_authority.key),
+        )?,
+        std::slice::from_ref(&receipt_mint),
+    )?;
+
+    anchor_spl::token_2022::initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            anchor_spl::token_2022::InitializeMint2 {
+                mint: receipt_mint.clone(),
+            },
+        ),
+        0,
+        receipt_authority.key,
+        None,
+    )?;
+
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::initialize(
+            &Token2022::id(),
+            receipt_mint.key,
+            receipt_authority.key,
+            receipt_mint.key,
+            receipt_authority.key,
+            RECEIPT_NAME.to_string(),
+            RECEIPT_SYMBOL.to_string(),
+            RECEIPT_URI.to_string(),
+        ),
+        &[receipt_mint.clone(), receipt_authority.clone()],
+        authority_seeds,
+    )?;
+    update_receipt_metadata(
+        &token_program,
+        &receipt_mint,
+        &receipt_authority,
+        authority_seeds,
+        fields,
+    )?;
+
+    // The owner's token account of an earlier receipt of this lockup PDA is left empty after a sale,
+    // and the new mint has the same address, so it's reused instead of failing the create
+    anchor_spl::associated_token::create_idempotent(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        anchor_spl::associated_token::Create {
+            payer: ctx.accounts.owner.to_account_info(),
+            associated_token: ctx.accounts.receipt_token_account.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+            mint: receipt_mint.clone(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    anchor_spl::token_2022::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            anchor_spl::token_2022::MintTo {
+                mint: receipt_mint.clone(),
+                to: ctx.accounts.receipt_token_account.to_account_info(),
+                authority: receipt_authority.clone(),
+            },
+            authority_seeds,
+        ),
+        1,
+    )?;
+
+    // the supply is fixed to 1, the authority only keeps updating the metadata
+    anchor_spl::token_2022::set_authority(
+        CpiContext::new_with_signer(
+            token_program,
+            anchor_spl::token_2022::SetAuthority {
+                current_authority: receipt_authority,
+                account_or_mint: receipt_mint.clone(),
+            },
+            authority_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    lockup.receipt_mint = receipt_mint.key();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::test_lockup;
+    use anchor_spl::token_2022::spl_token_2022::{
+        solana_program::program_pack::Pack,
+        state::{Account, AccountState, Mint},
+    };
+    use spl_token_metadata_interface::state::TokenMetadata;
+
+    fn receipt_token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> TokenAccount {
+        let mut data = vec![0; Account::LEN];
+        Account::pack(
+            Account {
+                mint,
+                owner,
+                amount,
+                state: AccountState::Initialized,
+                ..Default::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        TokenAccount::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_receipt_mint_len() {
+        let fields = vec![
+            ("amount".to_string(), "1000000".to_string()),
+            ("end_ts".to_string(), "1700000000".to_string()),
+            ("target_voting_pct".to_string(), "2000".to_string()),
+        ];
+        let metadata = TokenMetadata {
+            name: RECEIPT_NAME.to_string(),
+            symbol: RECEIPT_SYMBOL.to_string(),
+            uri: RECEIPT_URI.to_string(),
+            additional_metadata: fields.clone(),
+            ..Default::default()
+        };
+        let mint_len = ExtensionType::try_calculate_account_len::<Mint>(&[
+            ExtensionType::MetadataPointer,
+            ExtensionType::MintCloseAuthority,
+        ])
+        .unwrap();
+
+        assert_eq!(
+            receipt_mint_len(&fields).unwrap(),
+            mint_len + metadata.tlv_size_of().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_receipt_holder() {
+        let mut lockup = Lockup {
+            owner: Pubkey::new_unique(),
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + 86400 * 365,
+            receipt_mint: Pubkey::new_unique(),
+            ..test_lockup()
+        };
+        let owner = lockup.owner;
+        let buyer = Pubkey::new_unique();
+
+        // the owner holds the receipt after mint_lockup_receipt
+        let owner_receipt = receipt_token_account(lockup.receipt_mint, owner, 1);
+        assert!(is_receipt_holder(&lockup, &owner_receipt, &owner));
+        assert!(!is_receipt_holder(&lockup, &owner_receipt, &buyer));
+
+        // after a sale, only the buyer can vote, request the unstake and unstake
+        let owner_receipt = receipt_token_account(lockup.receipt_mint, owner, 0);
+        let buyer_receipt = receipt_token_account(lockup.receipt_mint, buyer, 1);
+        assert!(!is_receipt_holder(&lockup, &owner_receipt, &owner));
+        assert!(is_receipt_holder(&lockup, &buyer_receipt, &buyer));
+
+        // a receipt of another lockup doesn't count
+        let other_receipt = receipt_token_account(Pubkey::new_unique(), buyer, 1);
+        assert!(!is_receipt_holder(&lockup, &other_receipt, &buyer));
+
+        // once redeemed, the lockup has no receipt anymore
+        lockup.receipt_mint = Pubkey::default();
+        let empty_receipt = receipt_token_account(Pubkey::default(), buyer, 1);
+        assert!(!is_receipt_holder(&lockup, &empty_receipt, &buyer));
+    }
+}
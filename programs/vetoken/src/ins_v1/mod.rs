@@ -4,24 +4,105 @@ pub use init_namespace::*;
 pub mod update_namespace;
 pub use update_namespace::*;
 
+pub mod set_emergency_unlock;
+pub use set_emergency_unlock::*;
+
 pub mod stake;
 pub use stake::*;
 
+pub mod stake_for;
+pub use stake_for::*;
+
+pub mod stake_to_vesting;
+pub use stake_to_vesting::*;
+
+pub mod clawback;
+pub use clawback::*;
+
+pub mod relock;
+pub use relock::*;
+
 pub mod stake_to;
 pub use stake_to::*;
 
+pub mod request_unstake;
+pub use request_unstake::*;
+
 pub mod unstake;
 pub use unstake::*;
 
+pub mod unstake_partial;
+pub use unstake_partial::*;
+
+pub mod early_unstake;
+pub use early_unstake::*;
+
+pub mod withdraw_penalty;
+pub use withdraw_penalty::*;
+
+pub mod transfer_lockup;
+pub use transfer_lockup::*;
+
+pub mod merge_lockups;
+pub use merge_lockups::*;
+
+pub mod split_lockup;
+pub use split_lockup::*;
+
+pub mod set_lockup_perpetual;
+pub use set_lockup_perpetual::*;
+
+pub mod set_lockup_multipliers;
+pub use set_lockup_multipliers::*;
+
+pub mod mint_lockup_receipt;
+pub use mint_lockup_receipt::*;
+
+pub mod refresh_lockup_receipt;
+pub use refresh_lockup_receipt::*;
+
+pub mod request_unstake_with_receipt;
+pub use request_unstake_with_receipt::*;
+
+pub mod unstake_with_receipt;
+pub use unstake_with_receipt::*;
+
 pub mod init_proposal;
 pub use init_proposal::*;
 
 pub mod update_proposal;
 pub use update_proposal::*;
 
+pub mod cancel_proposal;
+pub use cancel_proposal::*;
+
+pub mod veto_proposal;
+pub use veto_proposal::*;
+
+pub mod finalize_proposal;
+pub use finalize_proposal::*;
+
 pub mod vote;
 pub use vote::*;
 
+pub mod change_vote;
+pub use change_vote::*;
+
+pub mod revoke_vote;
+pub use revoke_vote::*;
+
+pub mod delegate_lockup;
+pub use delegate_lockup::*;
+
+pub mod revoke_delegation;
+pub use revoke_delegation::*;
+
+pub mod vote_delegated;
+pub use vote_delegated::*;
+
+pub mod vote_with_receipt;
+pub use vote_with_receipt::*;
+
 pub mod init_distribution;
 pub use init_distribution::*;
 
@@ -0,0 +1,73 @@
+use crate::{
+    errors::CustomError,
+    ins_v1::mint_lockup_receipt::{
+        receipt_metadata_fields, receipt_mint_len, update_receipt_metadata,
+    },
+    receipt_authority_seeds,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token_2022::Token2022;
+
+// RefreshLockupReceipt means that anyone can sync the receipt metadata with the lockup.
+#[derive(Accounts)]
+pub struct RefreshLockupReceipt<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        has_one=ns,
+        constraint = lockup.has_receipt() @ CustomError::InvalidReceipt,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    /// CHECK: the receipt mint of the lockup, updated by token-2022
+    #[account(
+        mut,
+        address = lockup.receipt_mint @ CustomError::InvalidReceipt,
+    )]
+    receipt_mint: UncheckedAccount<'info>,
+
+    /// CHECK: PDA of the namespace, the metadata update authority of the receipts
+    #[account(
+        seeds=[b"receipt_authority", ns.key().as_ref()],
+        bump,
+    )]
+    receipt_authority: UncheckedAccount<'info>,
+
+    #[account()]
+    ns: Box<Account<'info, Namespace>>,
+
+    receipt_token_program: Program<'info, Token2022>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, RefreshLockupReceipt<'info>>) -> Result<()> {
+    let receipt_mint = ctx.accounts.receipt_mint.to_account_info();
+    let ns_key = ctx.accounts.ns.key();
+    let authority_bump = ctx.bumps.receipt_authority;
+    let fields = receipt_metadata_fields(&ctx.accounts.lockup);
+
+    // token-2022 reallocs the mint when the values get longer, top up the rent for it
+    let rent = Rent::get()?.minimum_balance(receipt_mint_len(&fields)?);
+    if receipt_mint.lamports() < rent {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: receipt_mint.clone(),
+                },
+            ),
+            rent - receipt_mint.lamports(),
+        )?;
+    }
+
+    update_receipt_metadata(
+        &ctx.accounts.receipt_token_program.to_account_info(),
+        &receipt_mint,
+        &ctx.accounts.receipt_authority.to_account_info(),
+        &[receipt_authority_seeds!(ns_key, authority_bump)],
+        fields,
+    )
+}
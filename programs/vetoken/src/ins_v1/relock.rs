@@ -0,0 +1,104 @@
+use crate::{
+    errors::CustomError,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RelockArgs {
+    amount: u64, // optional additional tokens to deposit, can be 0
+    end_ts: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(args:RelockArgs)]
+pub struct Relock<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = owner,
+        constraint = token_account.amount >= args.amount @ CustomError::InvalidTokenAmount,
+    )]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = lockup.effective_end_ts(&ns) <= ns.now() @ CustomError::InvalidTimestamp, // only expired lockups can be relocked, active ones can use stake
+        constraint = args.end_ts >= lockup.min_end_ts(&ns) @ CustomError::InvalidTimestamp,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = lockup,
+    )]
+    lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = token_mint,
+        constraint = !ns.emergency_unlock @ CustomError::EmergencyUnlocked,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, Relock<'info>>,
+    args: RelockArgs,
+) -> Result<()> {
+    let ns = &mut ctx.accounts.ns;
+    let lockup = &mut ctx.accounts.lockup;
+
+    lockup.seed_checkpoint();
+
+    if args.amount > 0 {
+        anchor_spl::token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::TransferChecked {
+                    from: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.lockup_token_account.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            args.amount,
+            ctx.accounts.token_mint.decimals,
+        )?; // Transfer the additional tokens to the lockup account
+    }
+
+    lockup.relock(ns, args.amount, args.end_ts)?;
+
+    ns.lockup_amount = ns
+        .lockup_amount
+        .checked_add(args.amount)
+        .expect("should not overflow");
+
+    lockup.write_checkpoint(ns);
+
+    if !lockup.valid(ns) {
+        return Err(CustomError::InvalidLockup.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::{
+    errors::CustomError,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account()]
+    owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.delegated @ CustomError::LockupDelegated,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = lockup.effective_end_ts(&ns) <= ns.now() @ CustomError::InvalidTimestamp,
+        constraint = lockup.unstake_requested_ts == 0 @ CustomError::InvalidLockup,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        constraint = ns.lockup_unstake_cooldown > 0 @ CustomError::InvalidNamespace,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, RequestUnstake<'info>>) -> Result<()> {
+    let lockup = &mut ctx.accounts.lockup;
+    lockup.unstake_requested_ts = ctx.accounts.ns.now();
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::{
+    errors::CustomError,
+    ins_v1::is_receipt_holder,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+pub struct RequestUnstakeWithReceipt<'info> {
+    #[account()]
+    holder: Signer<'info>,
+
+    #[account(
+        constraint = is_receipt_holder(&lockup, &receipt_token_account, &holder.key()) @ CustomError::InvalidReceipt,
+    )]
+    receipt_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), lockup.owner.as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        constraint = lockup.has_receipt() @ CustomError::InvalidReceipt,
+        constraint = lockup.effective_end_ts(&ns) <= ns.now() @ CustomError::InvalidTimestamp,
+        constraint = lockup.unstake_requested_ts == 0 @ CustomError::InvalidLockup,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        constraint = ns.lockup_unstake_cooldown > 0 @ CustomError::InvalidNamespace,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestUnstakeWithReceipt<'info>>,
+) -> Result<()> {
+    let lockup = &mut ctx.accounts.lockup;
+    lockup.unstake_requested_ts = ctx.accounts.ns.now();
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::{
+    errors::CustomError,
+    states::{Delegation, Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.has_active_votes(&ns) @ CustomError::LockupHasActiveVotes,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        seeds=[b"delegation", ns.key().as_ref(), lockup.key().as_ref()],
+        has_one=ns,
+        has_one=owner,
+        has_one=lockup,
+        bump,
+        close=owner,
+    )]
+    delegation: Box<Account<'info, Delegation>>,
+
+    #[account()]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, RevokeDelegation<'info>>) -> Result<()> {
+    ctx.accounts.lockup.delegated = false;
+
+    Ok(())
+}
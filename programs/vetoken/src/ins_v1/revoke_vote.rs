@@ -0,0 +1,45 @@
+use crate::{
+    errors::CustomError,
+    states::{Namespace, Proposal, VoteRecordV2, PROPOSAL_STATUS_ACTIVE},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeVote<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=ns,
+      constraint = ns.now() >= proposal.start_ts && ns.now() <= proposal.end_ts @ CustomError::InvalidTimestamp,
+      constraint = proposal.current_status(&ns) == PROPOSAL_STATUS_ACTIVE @ CustomError::InvalidProposalState,
+    )]
+    proposal: Box<Account<'info, Proposal>>,
+
+    // any kind of vote record, the signer of the vote is its owner: the lockup owner for
+    // vote_record, the delegate for delegated_vote_record and the holder for receipt_vote_record
+    #[account(
+      mut,
+      has_one=ns,
+      has_one=owner,
+      has_one=proposal,
+      close=owner,
+    )]
+    vote_record: Box<Account<'info, VoteRecordV2>>,
+
+    #[account()]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+// The lockups' voted_proposal_end_ts is left as is, it's only a lower bound
+// used to prevent unstaking during the votes.
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, RevokeVote<'info>>) -> Result<()> {
+    let vote_record = &ctx.accounts.vote_record;
+    ctx.accounts.proposal.revoke_split_vote(
+        &vote_record.cast_choice_weights_pct(),
+        vote_record.voting_power,
+    )?;
+
+    Ok(())
+}
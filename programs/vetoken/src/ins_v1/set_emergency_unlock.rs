@@ -0,0 +1,29 @@
+use crate::states::Namespace;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetEmergencyUnlockArgs {
+    emergency_unlock: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args:SetEmergencyUnlockArgs)]
+pub struct SetEmergencyUnlock<'info> {
+    #[account()]
+    security_council: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one = security_council,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetEmergencyUnlock<'info>>,
+    args: SetEmergencyUnlockArgs,
+) -> Result<()> {
+    ctx.accounts.ns.emergency_unlock = args.emergency_unlock;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::{
+    errors::CustomError,
+    events::LockupMultipliersUpdated,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetLockupMultipliersArgs {
+    target_voting_pct: Option<u16>,  // None keeps the current value
+    target_rewards_pct: Option<u16>, // None keeps the current value
+}
+
+// SetLockupMultipliers means that security_council is overriding the multipliers of a lockup.
+#[derive(Accounts)]
+#[instruction(args:SetLockupMultipliersArgs)]
+pub struct SetLockupMultipliers<'info> {
+    #[account()]
+    security_council: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), lockup.owner.as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        constraint = args.target_voting_pct.is_some() || args.target_rewards_pct.is_some() @ CustomError::InvalidLockup,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        has_one = security_council,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetLockupMultipliers<'info>>,
+    args: SetLockupMultipliersArgs,
+) -> Result<()> {
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &ctx.accounts.ns;
+    let old_target_voting_pct = lockup.target_voting_pct;
+    let old_target_rewards_pct = lockup.target_rewards_pct;
+
+    lockup.seed_checkpoint();
+
+    if let Some(target_voting_pct) = args.target_voting_pct {
+        lockup.target_voting_pct = target_voting_pct;
+    }
+    if let Some(target_rewards_pct) = args.target_rewards_pct {
+        lockup.target_rewards_pct = target_rewards_pct;
+    }
+
+    // only the bounds are checked, so the multipliers can be removed until the lockup is unstaked
+    if !lockup.valid_target_pcts() {
+        return Err(CustomError::InvalidLockup.into());
+    }
+
+    // the new multipliers don't apply to the proposals that have already started
+    lockup.write_checkpoint(ns);
+
+    emit!(LockupMultipliersUpdated {
+        ns: ns.key(),
+        lockup: lockup.key(),
+        security_council: ctx.accounts.security_council.key(),
+        old_target_voting_pct,
+        new_target_voting_pct: lockup.target_voting_pct,
+        old_target_rewards_pct,
+        new_target_rewards_pct: lockup.target_rewards_pct,
+        ts: ns.now(),
+    });
+
+    Ok(())
+}
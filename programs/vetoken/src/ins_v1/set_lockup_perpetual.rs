@@ -0,0 +1,65 @@
+use crate::{
+    errors::CustomError,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetLockupPerpetualArgs {
+    perpetual: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args:SetLockupPerpetualArgs)]
+pub struct SetLockupPerpetual<'info> {
+    #[account()]
+    owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = lockup.perpetual != args.perpetual @ CustomError::InvalidLockup,
+        constraint = lockup.effective_end_ts(&ns) > ns.now() @ CustomError::InvalidTimestamp, // expired lockups cannot become perpetual
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account()]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetLockupPerpetual<'info>>,
+    args: SetLockupPerpetualArgs,
+) -> Result<()> {
+    // Get the data length before creating the mutable borrow
+    let data_len = ctx.accounts.lockup.to_account_info().data_len();
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &ctx.accounts.ns;
+    let now = ns.now();
+
+    lockup.normalize_weighted_start_ts(data_len);
+    lockup.seed_checkpoint();
+
+    // in both cases the lockup is now locked for lockup_max_saturation from now on,
+    // when turning it off, the normal countdown starts from now
+    lockup.end_ts = lockup.effective_end_ts(ns);
+    if !args.perpetual {
+        lockup.start_ts = now;
+        lockup.end_ts = now
+            .checked_add(ns.lockup_max_saturation as i64)
+            .expect("should not overflow");
+    }
+    lockup.perpetual = args.perpetual;
+
+    lockup.write_checkpoint(ns);
+
+    if !lockup.valid(ns) {
+        return Err(CustomError::InvalidLockup.into());
+    }
+
+    Ok(())
+}
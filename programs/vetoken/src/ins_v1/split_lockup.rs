@@ -0,0 +1,122 @@
+use crate::{
+    errors::CustomError,
+    lockup_seeds,
+    states::{Lockup, LockupCheckpoint, Namespace, MAX_LOCKUP_CHECKPOINTS},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SplitLockupArgs {
+    amount: u64,    // amount to carve out into the new lockup
+    new_index: u32, // index of the new lockup for the owner
+}
+
+#[derive(Accounts)]
+#[instruction(args:SplitLockupArgs)]
+pub struct SplitLockup<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.delegated @ CustomError::LockupDelegated,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = lockup.unvested_amount(&ns) == 0 @ CustomError::LockupVesting,
+        constraint = args.amount > 0 && args.amount < lockup.amount @ CustomError::InvalidTokenAmount,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = lockup,
+        constraint = lockup_token_account.amount >= lockup.amount @ CustomError::InvalidLockupAmount,
+    )]
+    lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer=owner,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(args.new_index).as_ref()],
+        space= 8 + Lockup::INIT_SPACE,
+        bump
+    )]
+    new_lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = new_lockup,
+        payer = owner,
+    )]
+    new_lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        has_one = token_mint,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SplitLockup<'info>>,
+    args: SplitLockupArgs,
+) -> Result<()> {
+    // Get the data length before creating the mutable borrow
+    let data_len = ctx.accounts.lockup.to_account_info().data_len();
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &ctx.accounts.ns;
+    let owner = &ctx.accounts.owner;
+    let index = lockup.index;
+    let bump = ctx.bumps.lockup;
+
+    lockup.normalize_weighted_start_ts(data_len);
+    lockup.seed_checkpoint();
+
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                from: ctx.accounts.lockup_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.new_lockup_token_account.to_account_info(),
+                authority: lockup.to_account_info(),
+            },
+            &[lockup_seeds!(ns, owner, index, bump)],
+        ),
+        args.amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    let new_lockup = &mut ctx.accounts.new_lockup;
+    new_lockup.set_inner(lockup.split(args.amount, args.new_index));
+    // the new lockup didn't exist before, so it cannot reuse the history for votes
+    new_lockup.checkpoints = [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS];
+    new_lockup.checkpoint_count = 0;
+    new_lockup.write_checkpoint(ns);
+
+    lockup.write_checkpoint(ns);
+
+    if !lockup.valid(ns) || !new_lockup.valid(ns) {
+        return Err(CustomError::InvalidLockup.into());
+    }
+
+    Ok(())
+}
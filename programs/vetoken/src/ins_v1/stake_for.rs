@@ -0,0 +1,117 @@
+use crate::{
+    errors::CustomError,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StakeForArgs {
+    amount: u64,
+    end_ts: i64, // only used when creating the lockup, existing lockups keep their end_ts
+}
+
+// StakeFor means that anyone can stake tokens to the lockup account for the beneficiary.
+#[derive(Accounts)]
+#[instruction(args:StakeForArgs)]
+pub struct StakeFor<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: beneficiary is an input parameter, it will be the owner of the lockup account
+    #[account()]
+    beneficiary: UncheckedAccount<'info>,
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = payer,
+        constraint = token_account.amount >= args.amount @ CustomError::InvalidTokenAmount,
+    )]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      init_if_needed,
+      payer=payer,
+      seeds=[b"lockup", ns.key().as_ref(), beneficiary.key().as_ref()],
+      space= 8 + Lockup::INIT_SPACE,
+      bump // the amount and end_ts are checked by Lockup::stake_for
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = lockup,
+        payer = payer,
+    )]
+    lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = token_mint,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, StakeFor<'info>>,
+    args: StakeForArgs,
+) -> Result<()> {
+    let ns = &mut ctx.accounts.ns;
+
+    // Get the data length before creating the mutable borrow
+    let data_len = ctx.accounts.lockup.to_account_info().data_len();
+    let lockup = &mut ctx.accounts.lockup;
+
+    lockup.normalize_weighted_start_ts(data_len);
+    lockup.seed_checkpoint();
+
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                from: ctx.accounts.token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.lockup_token_account.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+            },
+        ),
+        args.amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    // the payer can only set the end_ts and multipliers of a new lockup,
+    // the tokens are added to an existing lockup without changing them
+    lockup.stake_for(
+        ns,
+        ns.key(),
+        ctx.accounts.beneficiary.key(),
+        args.amount,
+        args.end_ts,
+    )?;
+
+    ns.lockup_amount = ns
+        .lockup_amount
+        .checked_add(args.amount)
+        .expect("should not overflow");
+
+    lockup.write_checkpoint(ns);
+
+    if !lockup.valid(ns) {
+        return Err(CustomError::InvalidLockup.into());
+    }
+
+    Ok(())
+}
@@ -1,5 +1,3 @@
-use std::cmp::min;
-
 use crate::{
     errors::CustomError,
     states::{Lockup, Namespace},
@@ -11,7 +9,7 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct StakeToArgs {
     amount: u64,
-    end_ts: i64,
+    end_ts: i64, // only used when creating the lockup or when it was staked with end_ts 0, otherwise existing lockups keep their end_ts
     disable_rewards: bool, // optionally disable target_rewards_pct for the owner
 }
 
@@ -39,12 +37,13 @@ pub struct StakeTo<'info> {
     token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-      init, // we should assume that the person has not staked before
+      init_if_needed, // the person may have staked before, then stake_to tops up the existing lockup
       payer=security_council,
       seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref()],
       space= 8 + Lockup::INIT_SPACE,
-      constraint = args.amount >= ns.lockup_min_amount @ CustomError::InvalidLockupAmount,
-      constraint = (args.end_ts >= lockup.min_end_ts(&ns) || args.end_ts == 0) @ CustomError::InvalidTimestamp,
+      constraint = (args.amount >= ns.lockup_min_amount || (args.amount > 0 && lockup.amount != 0)) @ CustomError::InvalidLockupAmount,
+      constraint = (args.end_ts >= lockup.min_end_ts(&ns) || args.end_ts == 0 || lockup.amount != 0) @ CustomError::InvalidTimestamp,
+      constraint = (lockup.effective_end_ts(&ns) >= ns.now() || lockup.end_ts == 0) @ CustomError::InvalidTimestamp, // can only top up the lockup when it's still active
       bump
     )]
     lockup: Box<Account<'info, Lockup>>,
@@ -63,6 +62,7 @@ pub struct StakeTo<'info> {
         mut,
         has_one = token_mint,
         has_one = security_council,
+        constraint = !ns.emergency_unlock @ CustomError::EmergencyUnlocked,
     )]
     ns: Box<Account<'info, Namespace>>,
 
@@ -75,9 +75,15 @@ pub fn handle<'info>(
     ctx: Context<'_, '_, '_, 'info, StakeTo<'info>>,
     args: StakeToArgs,
 ) -> Result<()> {
-    let lockup = &mut ctx.accounts.lockup;
     let ns = &mut ctx.accounts.ns;
 
+    // Get the data length before creating the mutable borrow
+    let data_len = ctx.accounts.lockup.to_account_info().data_len();
+    let lockup = &mut ctx.accounts.lockup;
+
+    lockup.normalize_weighted_start_ts(data_len);
+    lockup.seed_checkpoint();
+
     anchor_spl::token_interface::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -92,26 +98,23 @@ pub fn handle<'info>(
         ctx.accounts.token_mint.decimals,
     )?;
 
+    // the council tops up an existing lockup without extending it, only a new lockup takes
+    // args.end_ts and the default multipliers
+    lockup.deposit_on_behalf(ns, args.amount, args.end_ts)?;
+    if args.disable_rewards {
+        lockup.target_rewards_pct = 0;
+    }
+
     lockup.ns = ns.key();
-    lockup.start_ts = ns.now();
-    lockup.end_ts = min(
-        args.end_ts,
-        lockup.start_ts + (ns.lockup_max_saturation as i64),
-    );
-    lockup.weighted_start_ts = lockup.start_ts;
-    lockup.amount = args.amount;
     lockup.owner = ctx.accounts.owner.key();
-    lockup.target_voting_pct = ns.lockup_default_target_voting_pct;
-    lockup.target_rewards_pct = match args.disable_rewards {
-        true => 0,
-        false => ns.lockup_default_target_rewards_pct,
-    };
 
     ns.lockup_amount = ns
         .lockup_amount
         .checked_add(args.amount)
         .expect("should not overflow");
 
+    lockup.write_checkpoint(ns);
+
     if !lockup.valid(ns) {
         return Err(CustomError::InvalidLockup.into());
     }
@@ -0,0 +1,131 @@
+use crate::{
+    errors::CustomError,
+    lockup_seeds,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransferLockupArgs {
+    new_index: u32, // index of the lockup for the new owner, 0 is the new owner's original lockup
+}
+
+#[derive(Accounts)]
+#[instruction(args:TransferLockupArgs)]
+pub struct TransferLockup<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account()]
+    new_owner: Signer<'info>, // new owner needs to consent to the transfer
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.delegated @ CustomError::LockupDelegated,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = !lockup.has_active_votes(&ns) @ CustomError::LockupHasActiveVotes,
+        constraint = lockup.unvested_amount(&ns) == 0 @ CustomError::LockupVesting,
+        bump,
+        close=owner,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = lockup,
+        constraint = lockup_token_account.amount >= lockup.amount @ CustomError::InvalidLockupAmount,
+    )]
+    lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer=owner,
+        seeds=[b"lockup", ns.key().as_ref(), new_owner.key().as_ref(), Lockup::index_seed(args.new_index).as_ref()],
+        space= 8 + Lockup::INIT_SPACE,
+        bump
+    )]
+    new_lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = new_lockup,
+        payer = owner,
+    )]
+    new_lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        has_one = token_mint,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferLockup<'info>>,
+    args: TransferLockupArgs,
+) -> Result<()> {
+    // Get the data length before creating the mutable borrow
+    let data_len = ctx.accounts.lockup.to_account_info().data_len();
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &ctx.accounts.ns;
+    let amount = lockup.amount;
+    let owner = &ctx.accounts.owner;
+    let index = lockup.index;
+    let bump = ctx.bumps.lockup;
+
+    lockup.normalize_weighted_start_ts(data_len);
+
+    if amount > 0 {
+        anchor_spl::token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::TransferChecked {
+                    from: ctx.accounts.lockup_token_account.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.new_lockup_token_account.to_account_info(),
+                    authority: lockup.to_account_info(),
+                },
+                &[lockup_seeds!(ns, owner, index, bump)],
+            ),
+            amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    if ctx.accounts.lockup_token_account.amount == amount {
+        anchor_spl::token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::CloseAccount {
+                account: ctx.accounts.lockup_token_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: lockup.to_account_info(),
+            },
+            &[lockup_seeds!(ns, owner, index, bump)],
+        ))?;
+    }
+
+    let new_lockup = &mut ctx.accounts.new_lockup;
+    new_lockup.set_inner(lockup.transferred(ctx.accounts.new_owner.key(), args.new_index));
+
+    lockup.amount = 0;
+
+    Ok(())
+}
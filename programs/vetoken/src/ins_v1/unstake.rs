@@ -1,7 +1,7 @@
 use crate::{
     errors::CustomError,
     lockup_seeds,
-    states::{Lockup, Namespace},
+    states::{Delegation, Lockup, Namespace},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -29,10 +29,14 @@ pub struct Unstake<'info> {
 
     #[account(
         mut,
-        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref()],
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
         has_one=ns,
         has_one=owner,
-        constraint = lockup.end_ts <= ns.now() @ CustomError::InvalidTimestamp,
+        constraint = lockup.can_unstake_delegated(&ns, delegation.is_some()) @ CustomError::LockupDelegated,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = (ns.emergency_unlock || lockup.effective_end_ts(&ns) <= ns.now()) @ CustomError::InvalidTimestamp, // end_ts is ignored in the emergency unlock mode
+        constraint = (ns.emergency_unlock || lockup.unstake_cooldown_ended(&ns)) @ CustomError::UnstakeCooldownNotEnded,
+        constraint = lockup.vested_amount(&ns) == lockup.amount @ CustomError::LockupVesting, // the unvested tokens are clawed back first, even in the emergency unlock mode
         constraint = ns.lockup_amount >= lockup.amount @ CustomError::Overflow,
         bump,
         close=owner,
@@ -48,6 +52,18 @@ pub struct Unstake<'info> {
     )]
     lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    // only in the emergency unlock mode, a delegated lockup closes its delegation on unstake
+    #[account(
+        mut,
+        seeds=[b"delegation", ns.key().as_ref(), lockup.key().as_ref()],
+        has_one=ns,
+        has_one=owner,
+        has_one=lockup,
+        bump,
+        close=owner,
+    )]
+    delegation: Option<Box<Account<'info, Delegation>>>,
+
     #[account(
         mut,
         has_one = token_mint,
@@ -64,6 +80,7 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Unstake<'info>>) -> Result<
     let ns = &mut ctx.accounts.ns;
     let amount = lockup.amount;
     let owner = &ctx.accounts.owner;
+    let index = lockup.index;
     let bump = ctx.bumps.lockup;
 
     anchor_spl::token_interface::transfer_checked(
@@ -75,7 +92,7 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Unstake<'info>>) -> Result<
                 to: ctx.accounts.token_account.to_account_info(),
                 authority: lockup.to_account_info(),
             },
-            &[lockup_seeds!(ns, owner, bump)],
+            &[lockup_seeds!(ns, owner, index, bump)],
         ),
         amount,
         ctx.accounts.token_mint.decimals,
@@ -89,7 +106,7 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Unstake<'info>>) -> Result<
                 destination: owner.to_account_info(),
                 authority: lockup.to_account_info(),
             },
-            &[lockup_seeds!(ns, owner, bump)],
+            &[lockup_seeds!(ns, owner, index, bump)],
         ))?;
     }
 
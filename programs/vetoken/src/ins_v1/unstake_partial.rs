@@ -0,0 +1,131 @@
+use crate::{
+    errors::CustomError,
+    lockup_seeds,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::{prelude::*, AccountsClose};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UnstakePartialArgs {
+    amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args:UnstakePartialArgs)]
+pub struct UnstakePartial<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = owner,
+        payer = owner,
+    )]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = !lockup.delegated @ CustomError::LockupDelegated,
+        constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+        constraint = (ns.emergency_unlock || lockup.effective_end_ts(&ns) <= ns.now()) @ CustomError::InvalidTimestamp, // end_ts is ignored in the emergency unlock mode
+        constraint = (ns.emergency_unlock || lockup.unstake_cooldown_ended(&ns)) @ CustomError::UnstakeCooldownNotEnded,
+        constraint = args.amount > 0 && args.amount <= lockup.amount @ CustomError::InvalidTokenAmount,
+        constraint = args.amount <= lockup.vested_amount(&ns) @ CustomError::LockupVesting,
+        constraint = ns.lockup_amount >= lockup.amount @ CustomError::Overflow,
+        bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = lockup,
+        constraint = lockup_token_account.amount >= lockup.amount @ CustomError::InvalidLockupAmount,
+    )]
+    lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = token_mint,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, UnstakePartial<'info>>,
+    args: UnstakePartialArgs,
+) -> Result<()> {
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &mut ctx.accounts.ns;
+    let owner = &ctx.accounts.owner;
+    let index = lockup.index;
+    let bump = ctx.bumps.lockup;
+
+    lockup.seed_checkpoint();
+
+    // falls back to a full unstake that closes the lockup if the remainder is too small
+    let amount = lockup.partial_unstake_amount(ns, args.amount)?;
+
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                from: ctx.accounts.lockup_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: lockup.to_account_info(),
+            },
+            &[lockup_seeds!(ns, owner, index, bump)],
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    lockup.amount = lockup
+        .amount
+        .checked_sub(amount)
+        .expect("underflow in reducing lockup.amount");
+
+    ns.lockup_amount = ns
+        .lockup_amount
+        .checked_sub(amount)
+        .expect("underflow in reducing ns.lockup_amount");
+
+    lockup.write_checkpoint(ns);
+
+    if lockup.amount == 0 {
+        if ctx.accounts.lockup_token_account.amount == amount {
+            anchor_spl::token_interface::close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::CloseAccount {
+                    account: ctx.accounts.lockup_token_account.to_account_info(),
+                    destination: owner.to_account_info(),
+                    authority: lockup.to_account_info(),
+                },
+                &[lockup_seeds!(ns, owner, index, bump)],
+            ))?;
+        }
+
+        lockup.close(owner.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use crate::{
+    errors::CustomError,
+    ins_v1::is_receipt_holder,
+    lockup_seeds, receipt_authority_seeds,
+    states::{Lockup, Namespace},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct UnstakeWithReceipt<'info> {
+    #[account(mut)]
+    holder: Signer<'info>,
+
+    /// CHECK: the original owner of the lockup, who paid the rent and gets it back
+    #[account(mut)]
+    owner: UncheckedAccount<'info>,
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = holder,
+        payer = holder,
+    )]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = lockup.receipt_mint @ CustomError::InvalidReceipt,
+    )]
+    receipt_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = receipt_token_program,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = holder,
+        constraint = is_receipt_holder(&lockup, &receipt_token_account, &holder.key()) @ CustomError::InvalidReceipt,
+    )]
+    receipt_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: PDA of the namespace, the close authority of the receipt mint
+    #[account(
+        seeds=[b"receipt_authority", ns.key().as_ref()],
+        bump,
+    )]
+    receipt_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+        has_one=ns,
+        has_one=owner,
+        constraint = lockup.has_receipt() @ CustomError::InvalidReceipt,
+        constraint = (ns.emergency_unlock || lockup.effective_end_ts(&ns) <= ns.now()) @ CustomError::InvalidTimestamp, // end_ts is ignored in the emergency unlock mode
+        constraint = (ns.emergency_unlock || lockup.unstake_cooldown_ended(&ns)) @ CustomError::UnstakeCooldownNotEnded,
+        constraint = ns.lockup_amount >= lockup.amount @ CustomError::Overflow,
+        bump,
+        close=owner,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = lockup,
+        constraint = lockup_token_account.amount >= lockup.amount @ CustomError::InvalidLockupAmount,
+    )]
+    lockup_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = token_mint,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    receipt_token_program: Program<'info, Token2022>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, UnstakeWithReceipt<'info>>) -> Result<()> {
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &mut ctx.accounts.ns;
+    let amount = lockup.amount;
+    let owner = &ctx.accounts.owner;
+    let index = lockup.index;
+    let bump = ctx.bumps.lockup;
+
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                from: ctx.accounts.lockup_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: lockup.to_account_info(),
+            },
+            &[lockup_seeds!(ns, owner, index, bump)],
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    if ctx.accounts.lockup_token_account.amount == amount {
+        anchor_spl::token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::CloseAccount {
+                account: ctx.accounts.lockup_token_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: lockup.to_account_info(),
+            },
+            &[lockup_seeds!(ns, owner, index, bump)],
+        ))?;
+    }
+
+    // the receipt is redeemed, burn it and give the rent of its token account back to the holder
+    anchor_spl::token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.receipt_token_program.to_account_info(),
+            anchor_spl::token_interface::Burn {
+                mint: ctx.accounts.receipt_mint.to_account_info(),
+                from: ctx.accounts.receipt_token_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+    anchor_spl::token_interface::close_account(CpiContext::new(
+        ctx.accounts.receipt_token_program.to_account_info(),
+        anchor_spl::token_interface::CloseAccount {
+            account: ctx.accounts.receipt_token_account.to_account_info(),
+            destination: ctx.accounts.holder.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    ))?;
+
+    // close the empty mint as well, so the lockup PDA can get a new receipt if it's staked again.
+    // The owner paid for the mint in mint_lockup_receipt and gets the rent back.
+    let ns_key = ns.key();
+    let authority_bump = ctx.bumps.receipt_authority;
+    anchor_spl::token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.receipt_token_program.to_account_info(),
+        anchor_spl::token_interface::CloseAccount {
+            account: ctx.accounts.receipt_mint.to_account_info(),
+            destination: owner.to_account_info(),
+            authority: ctx.accounts.receipt_authority.to_account_info(),
+        },
+        &[receipt_authority_seeds!(ns_key, authority_bump)],
+    ))?;
+
+    lockup.amount = 0;
+
+    ns.lockup_amount = ns
+        .lockup_amount
+        .checked_sub(amount)
+        .expect("underflow in reducing ns.lockup_amount");
+
+    Ok(())
+}
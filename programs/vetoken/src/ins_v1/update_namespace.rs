@@ -1,4 +1,7 @@
-use crate::{errors::CustomError, states::Namespace};
+use crate::{
+    errors::CustomError,
+    states::{Namespace, VotingCurvePoint, MAX_VOTING_CURVE_POINTS},
+};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -14,6 +17,10 @@ pub struct UpdateNamespaceArgs {
     proposal_min_voting_power_for_quorum: u64,
     proposal_min_pass_pct: u16,
     proposal_can_update_after_votes: bool,
+    lockup_early_unlock_penalty_pct: u16,
+    lockup_voting_power_mode: u8,
+    lockup_unstake_cooldown: i64,
+    lockup_voting_curve: Vec<VotingCurvePoint>, // empty keeps the linear voting power ramp
 }
 
 #[derive(Accounts)]
@@ -45,6 +52,17 @@ pub fn handle<'info>(
     ns.proposal_min_voting_power_for_quorum = args.proposal_min_voting_power_for_quorum;
     ns.proposal_min_pass_pct = args.proposal_min_pass_pct;
     ns.proposal_can_update_after_votes = false; // we don't allow this to be updated yet
+    ns.lockup_early_unlock_penalty_pct = args.lockup_early_unlock_penalty_pct;
+    ns.lockup_voting_power_mode = args.lockup_voting_power_mode;
+    ns.lockup_unstake_cooldown = args.lockup_unstake_cooldown;
+
+    if args.lockup_voting_curve.len() > MAX_VOTING_CURVE_POINTS {
+        return Err(CustomError::InvalidNamespace.into());
+    }
+    ns.lockup_voting_curve = [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS];
+    ns.lockup_voting_curve[..args.lockup_voting_curve.len()]
+        .copy_from_slice(&args.lockup_voting_curve);
+    ns.lockup_voting_curve_len = args.lockup_voting_curve.len() as u8;
 
     if !ns.valid() {
         return Err(CustomError::InvalidNamespace.into());
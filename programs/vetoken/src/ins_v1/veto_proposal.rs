@@ -0,0 +1,29 @@
+use crate::{
+    errors::CustomError,
+    states::{Namespace, Proposal, PROPOSAL_STATUS_VETOED},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    #[account()]
+    security_council: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=ns,
+      constraint = !proposal.is_finalized() @ CustomError::InvalidProposalState,
+    )]
+    proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+      has_one=security_council,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, VetoProposal<'info>>) -> Result<()> {
+    ctx.accounts.proposal.status = PROPOSAL_STATUS_VETOED;
+
+    Ok(())
+}
@@ -1,12 +1,14 @@
 use crate::{
     errors::CustomError,
-    states::{Lockup, Namespace, Proposal, VoteRecord},
+    states::{Lockup, Namespace, Proposal, VoteRecordV2, MAX_VOTE_LOCKUPS, PROPOSAL_STATUS_ACTIVE},
 };
 use anchor_lang::prelude::*;
+use std::cmp::max;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct VoteArgs {
     choice: u8,
+    choice_weights_pct: Vec<u8>, // optional split of the voting power over the choices, must sum up to 100 with the largest weight at choice
 }
 
 #[derive(Accounts)]
@@ -19,15 +21,20 @@ pub struct Vote<'info> {
       mut,
       has_one=ns,
       constraint = ns.now() >= proposal.start_ts && ns.now() <= proposal.end_ts @ CustomError::InvalidTimestamp,
+      constraint = proposal.current_status(&ns) == PROPOSAL_STATUS_ACTIVE @ CustomError::InvalidProposalState,
+      constraint = proposal.valid_choice(args.choice, &args.choice_weights_pct) @ CustomError::InvalidChoice,
     )]
     proposal: Box<Account<'info, Proposal>>,
 
     #[account(
-      seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref()],
+      mut,
+      seeds=[b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(lockup.index).as_ref()],
       has_one=owner,
       has_one=ns,
-      constraint = lockup.voting_power(&ns) > 0 @ CustomError::InvalidVotingPower,
-      constraint = lockup.end_ts > proposal.end_ts @ CustomError::InvalidTimestamp,
+      constraint = !lockup.delegated @ CustomError::LockupDelegated,
+      constraint = !lockup.has_receipt() @ CustomError::LockupHasReceipt,
+      constraint = lockup.voting_power_at(&ns, proposal.start_ts) > 0 @ CustomError::InvalidVotingPower,
+      constraint = lockup.effective_end_ts(&ns) > proposal.end_ts @ CustomError::InvalidTimestamp,
       bump,
     )]
     lockup: Box<Account<'info, Lockup>>,
@@ -36,36 +43,103 @@ pub struct Vote<'info> {
       init,
       seeds=[b"vote_record", ns.key().as_ref(), owner.key().as_ref(), proposal.key().as_ref()],
       payer=owner,
-      space=8 + VoteRecord::INIT_SPACE,
+      space=8 + VoteRecordV2::INIT_SPACE,
       bump,
     )]
-    vote_record: Box<Account<'info, VoteRecord>>,
+    vote_record: Box<Account<'info, VoteRecordV2>>,
 
-    #[account()]
+    #[account(
+        constraint = !ns.emergency_unlock @ CustomError::EmergencyUnlocked,
+    )]
     ns: Box<Account<'info, Namespace>>,
 
     system_program: Program<'info, System>,
 }
 
-pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Vote<'info>>, args: VoteArgs) -> Result<()> {
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Vote<'info>>,
+    args: VoteArgs,
+) -> Result<()> {
     let proposal = &mut ctx.accounts.proposal;
-    let lockup = &ctx.accounts.lockup;
+    let lockup = &mut ctx.accounts.lockup;
     let ns = &ctx.accounts.ns;
     let vote_record = &mut ctx.accounts.vote_record;
-    let voting_power = lockup.voting_power(ns);
+    let mut voting_power = lockup.voting_power_at(ns, proposal.start_ts);
 
-    proposal.cast_vote(args.choice, voting_power);
+    let mut lockup_keys = vec![lockup.key()];
+    voting_power = voting_power
+        .checked_add(count_other_lockups(
+            ns,
+            proposal,
+            &ctx.accounts.owner.key(),
+            &mut lockup_keys,
+            ctx.remaining_accounts,
+            ctx.program_id,
+        )?)
+        .expect("should not overflow");
+
+    lockup.voted_proposal_end_ts = max(lockup.voted_proposal_end_ts, proposal.end_ts);
 
     vote_record.ns = ns.key();
-    vote_record.choice = args.choice;
+    vote_record.set_choice_weights(args.choice, &args.choice_weights_pct);
     vote_record.owner = ctx.accounts.owner.key();
-    vote_record.proposal = ctx.accounts.proposal.key();
+    vote_record.proposal = proposal.key();
     vote_record.voting_power = voting_power;
     vote_record.lockup = ctx.accounts.lockup.key();
+    vote_record.lockups[..lockup_keys.len()].copy_from_slice(&lockup_keys);
 
     if !vote_record.valid() {
         return Err(CustomError::InvalidVoteRecord.into());
     }
 
+    proposal.cast_split_vote(&vote_record.choice_weights_pct, voting_power)?;
+
     Ok(())
 }
+
+// Sum up the voting power of the owner's other lockups passed as remaining accounts,
+// they need to pass the same checks as the primary lockup account of the vote.
+pub(crate) fn count_other_lockups<'info>(
+    ns: &Account<'info, Namespace>,
+    proposal: &Proposal,
+    owner: &Pubkey,
+    lockup_keys: &mut Vec<Pubkey>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<u64> {
+    let mut voting_power: u64 = 0;
+    for account_info in remaining_accounts.iter() {
+        let mut other_lockup = Account::<Lockup>::try_from(account_info)?;
+        if lockup_keys.len() >= MAX_VOTE_LOCKUPS
+            || lockup_keys.contains(&other_lockup.key())
+            || other_lockup.ns != ns.key()
+            || other_lockup.owner != *owner
+        {
+            return Err(CustomError::InvalidLockup.into());
+        }
+        if other_lockup.delegated {
+            return Err(CustomError::LockupDelegated.into());
+        }
+        if other_lockup.has_receipt() {
+            return Err(CustomError::LockupHasReceipt.into());
+        }
+        if other_lockup.effective_end_ts(ns) <= proposal.end_ts {
+            return Err(CustomError::InvalidTimestamp.into());
+        }
+        let other_voting_power = other_lockup.voting_power_at(ns, proposal.start_ts);
+        if other_voting_power == 0 {
+            return Err(CustomError::InvalidVotingPower.into());
+        }
+
+        lockup_keys.push(other_lockup.key());
+        voting_power = voting_power
+            .checked_add(other_voting_power)
+            .expect("should not overflow");
+
+        other_lockup.voted_proposal_end_ts =
+            max(other_lockup.voted_proposal_end_ts, proposal.end_ts);
+        other_lockup.exit(program_id)?;
+    }
+
+    Ok(voting_power)
+}
@@ -0,0 +1,92 @@
+use crate::{
+    errors::CustomError,
+    states::{Delegation, Lockup, Namespace, Proposal, VoteRecordV2, PROPOSAL_STATUS_ACTIVE},
+};
+use anchor_lang::prelude::*;
+use std::cmp::max;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VoteDelegatedArgs {
+    choice: u8,
+    choice_weights_pct: Vec<u8>, // optional split of the voting power over the choices, must sum up to 100 with the largest weight at choice
+}
+
+#[derive(Accounts)]
+#[instruction(args:VoteDelegatedArgs)]
+pub struct VoteDelegated<'info> {
+    #[account(mut)]
+    delegate: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=ns,
+      constraint = ns.now() >= proposal.start_ts && ns.now() <= proposal.end_ts @ CustomError::InvalidTimestamp,
+      constraint = proposal.current_status(&ns) == PROPOSAL_STATUS_ACTIVE @ CustomError::InvalidProposalState,
+      constraint = proposal.valid_choice(args.choice, &args.choice_weights_pct) @ CustomError::InvalidChoice,
+    )]
+    proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+      init,
+      seeds=[b"delegated_vote_record", ns.key().as_ref(), delegate.key().as_ref(), proposal.key().as_ref()],
+      payer=delegate,
+      space=8 + VoteRecordV2::INIT_SPACE,
+      bump,
+    )]
+    vote_record: Box<Account<'info, VoteRecordV2>>,
+
+    #[account(
+        constraint = !ns.emergency_unlock @ CustomError::EmergencyUnlocked,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    system_program: Program<'info, System>,
+}
+
+// The delegated lockups are passed as remaining accounts in pairs of (delegation, lockup)
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VoteDelegated<'info>>,
+    args: VoteDelegatedArgs,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let ns = &ctx.accounts.ns;
+    let delegate = &ctx.accounts.delegate;
+    let vote_record = &mut ctx.accounts.vote_record;
+
+    if !Delegation::valid_pairs_len(ctx.remaining_accounts.len()) {
+        return Err(CustomError::InvalidDelegation.into());
+    }
+
+    let mut lockups = vec![];
+    let mut pairs = vec![];
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let delegation = Account::<Delegation>::try_from(&accounts[0])?;
+        let lockup = Account::<Lockup>::try_from(&accounts[1])?;
+        pairs.push((*delegation, lockup.key(), *lockup));
+        lockups.push(lockup);
+    }
+    let (voting_power, lockup_keys) =
+        Delegation::delegated_voting_power(ns, &ns.key(), proposal, &delegate.key(), &pairs)?;
+
+    for lockup in lockups.iter_mut() {
+        lockup.voted_proposal_end_ts = max(lockup.voted_proposal_end_ts, proposal.end_ts);
+        lockup.exit(ctx.program_id)?;
+    }
+
+    vote_record.ns = ns.key();
+    vote_record.set_choice_weights(args.choice, &args.choice_weights_pct);
+    vote_record.owner = delegate.key();
+    vote_record.delegate = delegate.key();
+    vote_record.proposal = proposal.key();
+    vote_record.voting_power = voting_power;
+    vote_record.lockup = lockup_keys[0];
+    vote_record.lockups[..lockup_keys.len()].copy_from_slice(&lockup_keys);
+
+    if !vote_record.valid() {
+        return Err(CustomError::InvalidVoteRecord.into());
+    }
+
+    proposal.cast_split_vote(&vote_record.choice_weights_pct, voting_power)?;
+
+    Ok(())
+}
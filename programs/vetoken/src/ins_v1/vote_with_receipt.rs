@@ -0,0 +1,92 @@
+use crate::{
+    errors::CustomError,
+    ins_v1::is_receipt_holder,
+    states::{Lockup, Namespace, Proposal, VoteRecordV2, PROPOSAL_STATUS_ACTIVE},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use std::cmp::max;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VoteWithReceiptArgs {
+    choice: u8,
+    choice_weights_pct: Vec<u8>, // optional split of the voting power over the choices, must sum up to 100 with the largest weight at choice
+}
+
+#[derive(Accounts)]
+#[instruction(args:VoteWithReceiptArgs)]
+pub struct VoteWithReceipt<'info> {
+    #[account(mut)]
+    holder: Signer<'info>,
+
+    #[account(
+        constraint = is_receipt_holder(&lockup, &receipt_token_account, &holder.key()) @ CustomError::InvalidReceipt,
+    )]
+    receipt_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      mut,
+      has_one=ns,
+      constraint = ns.now() >= proposal.start_ts && ns.now() <= proposal.end_ts @ CustomError::InvalidTimestamp,
+      constraint = proposal.current_status(&ns) == PROPOSAL_STATUS_ACTIVE @ CustomError::InvalidProposalState,
+      constraint = proposal.valid_choice(args.choice, &args.choice_weights_pct) @ CustomError::InvalidChoice,
+    )]
+    proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+      mut,
+      seeds=[b"lockup", ns.key().as_ref(), lockup.owner.as_ref(), Lockup::index_seed(lockup.index).as_ref()],
+      has_one=ns,
+      constraint = lockup.has_receipt() @ CustomError::InvalidReceipt,
+      constraint = lockup.voting_power_at(&ns, proposal.start_ts) > 0 @ CustomError::InvalidVotingPower,
+      constraint = lockup.effective_end_ts(&ns) > proposal.end_ts @ CustomError::InvalidTimestamp,
+      bump,
+    )]
+    lockup: Box<Account<'info, Lockup>>,
+
+    // seeded by the lockup instead of the holder, so the receipt cannot vote again after changing hands
+    #[account(
+      init,
+      seeds=[b"receipt_vote_record", ns.key().as_ref(), lockup.key().as_ref(), proposal.key().as_ref()],
+      payer=holder,
+      space=8 + VoteRecordV2::INIT_SPACE,
+      bump,
+    )]
+    vote_record: Box<Account<'info, VoteRecordV2>>,
+
+    #[account(
+        constraint = !ns.emergency_unlock @ CustomError::EmergencyUnlocked,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, VoteWithReceipt<'info>>,
+    args: VoteWithReceiptArgs,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let lockup = &mut ctx.accounts.lockup;
+    let ns = &ctx.accounts.ns;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let voting_power = lockup.voting_power_at(ns, proposal.start_ts);
+
+    lockup.voted_proposal_end_ts = max(lockup.voted_proposal_end_ts, proposal.end_ts);
+
+    vote_record.ns = ns.key();
+    vote_record.set_choice_weights(args.choice, &args.choice_weights_pct);
+    vote_record.owner = ctx.accounts.holder.key();
+    vote_record.proposal = proposal.key();
+    vote_record.voting_power = voting_power;
+    vote_record.lockup = lockup.key();
+    vote_record.lockups[0] = lockup.key();
+
+    if !vote_record.valid() {
+        return Err(CustomError::InvalidVoteRecord.into());
+    }
+
+    proposal.cast_split_vote(&vote_record.choice_weights_pct, voting_power)?;
+
+    Ok(())
+}
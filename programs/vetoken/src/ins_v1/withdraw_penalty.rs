@@ -0,0 +1,71 @@
+use crate::{namespace_seeds, states::Namespace};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawPenalty<'info> {
+    #[account(mut)]
+    security_council: Signer<'info>,
+
+    #[account()]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+      mut,
+      associated_token::token_program = token_program,
+      associated_token::mint = token_mint,
+      associated_token::authority = ns,
+    )]
+    penalty_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      init_if_needed,
+      token::token_program = token_program,
+      associated_token::token_program = token_program,
+      associated_token::mint = token_mint,
+      associated_token::authority = security_council,
+      payer = security_council,
+    )]
+    security_council_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      seeds=[b"namespace", token_mint.key().as_ref(), ns.deployer.as_ref()],
+      has_one = token_mint,
+      has_one = security_council,
+      bump,
+    )]
+    ns: Box<Account<'info, Namespace>>,
+
+    token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawPenalty<'info>>) -> Result<()> {
+    let token_mint = &ctx.accounts.token_mint;
+    let deployer = ctx.accounts.ns.deployer;
+    let bump = ctx.bumps.ns;
+
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                from: ctx.accounts.penalty_token_account.to_account_info(),
+                mint: token_mint.to_account_info(),
+                to: ctx
+                    .accounts
+                    .security_council_token_account
+                    .to_account_info(),
+                authority: ctx.accounts.ns.to_account_info(),
+            },
+            &[namespace_seeds!(token_mint, deployer, bump)],
+        ),
+        ctx.accounts.penalty_token_account.amount,
+        token_mint.decimals,
+    )?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 mod errors;
+mod events;
 mod ins_v1;
 mod states;
 
@@ -26,9 +27,23 @@ pub mod vetoken {
         ins_v1::update_namespace::handle(ctx, args)
     }
 
+    // SetEmergencyUnlock will let the security council put the namespace into the
+    // emergency unlock mode as a wind-down path. In that mode everyone can unstake
+    // regardless of end_ts, and stake, stake_to and vote are rejected. Vesting lockups
+    // only release their vested tokens, the security council claws back the unvested ones
+    // before the rest can be unstaked.
+    pub fn set_emergency_unlock<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetEmergencyUnlock<'info>>,
+        args: SetEmergencyUnlockArgs,
+    ) -> Result<()> {
+        ins_v1::set_emergency_unlock::handle(ctx, args)
+    }
+
     // Stake will upsert a lockup account and lock the tokens for the
     // lockup duration to get the voting power and rewards multiplier
-    // data set.
+    // data set. The lockup account is also seeded by args.index, so that one
+    // owner can keep multiple independent lockups with different end_ts. Index 0
+    // is the original lockup.
     pub fn stake<'info>(
         ctx: Context<'_, '_, '_, 'info, Stake<'info>>,
         args: StakeArgs,
@@ -36,12 +51,22 @@ pub mod vetoken {
         ins_v1::stake::handle(ctx, args)
     }
 
+    // Relock will restart an expired lockup in place with a new end_ts, optionally
+    // depositing more tokens, instead of unstaking and staking again.
+    pub fn relock<'info>(
+        ctx: Context<'_, '_, '_, 'info, Relock<'info>>,
+        args: RelockArgs,
+    ) -> Result<()> {
+        ins_v1::relock::handle(ctx, args)
+    }
+
     // StakeTo will allow security council to deposit and lock the tokens
     // for a user. It's identical to Stake but security council can also
     // disable the rewards for that user.
     //
-    // Note that, if the user already has a lockup account, this will failed
-    // because of the anchor 'init' macro check.
+    // If the user already has an active lockup account, the tokens are added
+    // to it with the same weighted start_ts math as Stake, and the lockup keeps
+    // its current target_rewards_pct unless the rewards are disabled.
     pub fn stake_to<'info>(
         ctx: Context<'_, '_, '_, 'info, StakeTo<'info>>,
         args: StakeToArgs,
@@ -49,14 +74,170 @@ pub mod vetoken {
         ins_v1::stake_to::handle(ctx, args)
     }
 
+    // StakeFor will allow anyone to deposit and lock the tokens for a beneficiary,
+    // e.g. exchanges and DAO treasuries. A new lockup gets the namespace default
+    // multipliers, and an existing lockup keeps its end_ts and multipliers.
+    pub fn stake_for<'info>(
+        ctx: Context<'_, '_, '_, 'info, StakeFor<'info>>,
+        args: StakeForArgs,
+    ) -> Result<()> {
+        ins_v1::stake_for::handle(ctx, args)
+    }
+
+    // StakeToVesting will allow security council to lock the tokens of a grant for
+    // a user in a new lockup with a cliff and a linear release schedule. Only the
+    // vested tokens have voting power, and the lockup cannot be early unstaked,
+    // merged or split until it's fully vested.
+    pub fn stake_to_vesting<'info>(
+        ctx: Context<'_, '_, '_, 'info, StakeToVesting<'info>>,
+        args: StakeToVestingArgs,
+    ) -> Result<()> {
+        ins_v1::stake_to_vesting::handle(ctx, args)
+    }
+
+    // Clawback will allow security council to take back the unvested tokens of a
+    // vesting lockup, e.g. when a contributor leaves or before a vesting lockup is
+    // unstaked in the emergency unlock mode. The vested tokens stay locked in the
+    // lockup for the owner. A delegated lockup passes its delegation, which is
+    // closed together with the lockup when nothing is left in it.
+    pub fn clawback<'info>(ctx: Context<'_, '_, '_, 'info, Clawback<'info>>) -> Result<()> {
+        ins_v1::clawback::handle(ctx)
+    }
+
+    // RequestUnstake will start the unstake cooldown of an expired lockup when the
+    // namespace has lockup_unstake_cooldown set. The lockup has no voting power from
+    // then on, and Unstake is only allowed after the cooldown.
+    pub fn request_unstake<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestUnstake<'info>>,
+    ) -> Result<()> {
+        ins_v1::request_unstake::handle(ctx)
+    }
+
     // Unstake will remove the lockup account and return the tokens back
     // to the owner.
-    // Users can only unstake if the lockup period has ended.
+    // Users can only unstake if the lockup period has ended. In the emergency unlock
+    // mode a delegated lockup can be unstaked too, and its delegation is closed with it.
     pub fn unstake<'info>(ctx: Context<'_, '_, '_, 'info, Unstake<'info>>) -> Result<()> {
         ins_v1::unstake::handle(ctx)
     }
 
-    // Review council can create a proposal.
+    // UnstakePartial will return part of the tokens back to the owner and
+    // keep the lockup account open.
+    // Users can only unstake if the lockup period has ended. If the remaining
+    // amount would be below the lockup_min_amount, it falls back to a full unstake,
+    // or fails if the remaining amount is still vesting.
+    pub fn unstake_partial<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakePartial<'info>>,
+        args: UnstakePartialArgs,
+    ) -> Result<()> {
+        ins_v1::unstake_partial::handle(ctx, args)
+    }
+
+    // EarlyUnstake will remove the lockup account before the lockup period has ended.
+    // A penalty that scales with the remaining lockup time is sent to the namespace's
+    // penalty vault, and the rest of the tokens are returned to the owner.
+    // It's disabled when the namespace's lockup_early_unlock_penalty_pct is 0.
+    // Lockups that have voted on a proposal can only be unstaked early after the proposal ends.
+    pub fn early_unstake<'info>(
+        ctx: Context<'_, '_, '_, 'info, EarlyUnstake<'info>>,
+    ) -> Result<()> {
+        ins_v1::early_unstake::handle(ctx)
+    }
+
+    // withdraw_penalty will let security council withdraw the early unlock penalties
+    // from the namespace's penalty vault for redistribution
+    pub fn withdraw_penalty<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawPenalty<'info>>,
+    ) -> Result<()> {
+        ins_v1::withdraw_penalty::handle(ctx)
+    }
+
+    // TransferLockup will move the lockup to a new owner, both the owner and the
+    // new owner need to sign. The lockup is re-created under the new owner's seeds
+    // with the same amount, timestamps and multipliers.
+    // Lockups that have voted on a proposal can only be transferred after the proposal ends.
+    pub fn transfer_lockup<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferLockup<'info>>,
+        args: TransferLockupArgs,
+    ) -> Result<()> {
+        ins_v1::transfer_lockup::handle(ctx, args)
+    }
+
+    // MergeLockups will fold the owner's source lockup into the destination lockup.
+    // The merged lockup takes the later end_ts and conserves the time-weighted area
+    // of both lockups. The source lockup and its token account are closed.
+    // Both lockups need to be active and have the same multipliers.
+    pub fn merge_lockups<'info>(
+        ctx: Context<'_, '_, '_, 'info, MergeLockups<'info>>,
+    ) -> Result<()> {
+        ins_v1::merge_lockups::handle(ctx)
+    }
+
+    // SplitLockup will carve the amount out of the owner's lockup into a new lockup
+    // with the same end_ts, effective start and multipliers.
+    // Both lockups need to be valid after the split. Use transfer_lockup afterwards
+    // to move the new lockup to another wallet.
+    pub fn split_lockup<'info>(
+        ctx: Context<'_, '_, '_, 'info, SplitLockup<'info>>,
+        args: SplitLockupArgs,
+    ) -> Result<()> {
+        ins_v1::split_lockup::handle(ctx, args)
+    }
+
+    // SetLockupPerpetual will turn the lockup's perpetual flag on or off.
+    // Perpetual lockups always count as if end_ts = now + lockup_max_saturation, so they
+    // keep the full voting power and cannot be unstaked. Turning it off starts the
+    // normal countdown from lockup_max_saturation.
+    pub fn set_lockup_perpetual<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetLockupPerpetual<'info>>,
+        args: SetLockupPerpetualArgs,
+    ) -> Result<()> {
+        ins_v1::set_lockup_perpetual::handle(ctx, args)
+    }
+
+    // SetLockupMultipliers will allow security council to override the target_voting_pct
+    // and target_rewards_pct of an existing lockup, e.g. to boost strategic partners.
+    // The old and new values are emitted as an event for the audit trail.
+    pub fn set_lockup_multipliers<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetLockupMultipliers<'info>>,
+        args: SetLockupMultipliersArgs,
+    ) -> Result<()> {
+        ins_v1::set_lockup_multipliers::handle(ctx, args)
+    }
+
+    // MintLockupReceipt will mint a Token-2022 NFT that represents the lockup position,
+    // with the amount, end_ts and multiplier in its token metadata. From then on, the
+    // holder of the NFT acts as the owner, and the owner-signed lockup instructions
+    // are rejected.
+    pub fn mint_lockup_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintLockupReceipt<'info>>,
+    ) -> Result<()> {
+        ins_v1::mint_lockup_receipt::handle(ctx)
+    }
+
+    // RefreshLockupReceipt will sync the receipt metadata with the lockup, anyone can call it.
+    pub fn refresh_lockup_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefreshLockupReceipt<'info>>,
+    ) -> Result<()> {
+        ins_v1::refresh_lockup_receipt::handle(ctx)
+    }
+
+    // RequestUnstakeWithReceipt is identical to RequestUnstake, but signed by the receipt holder.
+    pub fn request_unstake_with_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestUnstakeWithReceipt<'info>>,
+    ) -> Result<()> {
+        ins_v1::request_unstake_with_receipt::handle(ctx)
+    }
+
+    // UnstakeWithReceipt will return the tokens to the receipt holder, burn the receipt and
+    // close its mint. The rent of the lockup and the mint goes back to the original owner who paid it.
+    pub fn unstake_with_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakeWithReceipt<'info>>,
+    ) -> Result<()> {
+        ins_v1::unstake_with_receipt::handle(ctx)
+    }
+
+    // Review council can create a proposal with its number of choices and optional short labels.
     pub fn init_proposal<'info>(
         ctx: Context<'_, '_, '_, 'info, InitProposal<'info>>,
         args: InitProposalArgs,
@@ -72,11 +253,90 @@ pub mod vetoken {
         ins_v1::update_proposal::handle(ctx, args)
     }
 
+    // CancelProposal will let the review council withdraw a proposal before start_ts.
+    pub fn cancel_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelProposal<'info>>,
+    ) -> Result<()> {
+        ins_v1::cancel_proposal::handle(ctx)
+    }
+
+    // VetoProposal will let the security council veto a proposal at any time before it's
+    // finalized, e.g. malicious or mistaken proposals. Vetoed proposals reject new votes.
+    pub fn veto_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, VetoProposal<'info>>,
+    ) -> Result<()> {
+        ins_v1::veto_proposal::handle(ctx)
+    }
+
+    // FinalizeProposal will evaluate the quorum and the pass threshold of an ended proposal,
+    // and write the status and the winning choice into the proposal. Anyone can call it.
+    pub fn finalize_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeProposal<'info>>,
+    ) -> Result<()> {
+        ins_v1::finalize_proposal::handle(ctx)
+    }
+
     // Users with voting power greater 0 and stake longer than the proposal duration can vote.
-    pub fn vote<'info>(ctx: Context<'_, '_, '_, 'info, Vote<'info>>, args: VoteArgs) -> Result<()> {
+    // The owner's other lockups can be passed as remaining accounts to sum up their voting power.
+    // They need to be writable, because their voted_proposal_end_ts is updated as well.
+    // The voting power is evaluated as of proposal.start_ts with the lockup checkpoints.
+    pub fn vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Vote<'info>>,
+        args: VoteArgs,
+    ) -> Result<()> {
         ins_v1::vote::handle(ctx, args)
     }
 
+    // ChangeVote will move the owner's vote to another choice while the proposal is active,
+    // the voting power is re-evaluated with the lockups passed like in Vote. Delegated and
+    // receipt votes are changed with RevokeVote and voting again.
+    pub fn change_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ChangeVote<'info>>,
+        args: ChangeVoteArgs,
+    ) -> Result<()> {
+        ins_v1::change_vote::handle(ctx, args)
+    }
+
+    // RevokeVote will drop a vote while the proposal is active and close the vote record. It's
+    // signed by whoever cast the vote: the owner, the delegate or the receipt holder.
+    pub fn revoke_vote<'info>(ctx: Context<'_, '_, '_, 'info, RevokeVote<'info>>) -> Result<()> {
+        ins_v1::revoke_vote::handle(ctx)
+    }
+
+    // DelegateLockup will let the owner delegate the lockup's voting power to a delegate.
+    // Delegated lockups can only vote through the delegate, and cannot be unstaked,
+    // transferred, merged or split until the delegation is revoked.
+    pub fn delegate_lockup<'info>(
+        ctx: Context<'_, '_, '_, 'info, DelegateLockup<'info>>,
+    ) -> Result<()> {
+        ins_v1::delegate_lockup::handle(ctx)
+    }
+
+    // RevokeDelegation will remove the delegation and give the voting power back to the owner.
+    // Both delegating and revoking wait until the proposals the lockup has voted on have ended.
+    pub fn revoke_delegation<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeDelegation<'info>>,
+    ) -> Result<()> {
+        ins_v1::revoke_delegation::handle(ctx)
+    }
+
+    // VoteDelegated will let a delegate vote with the voting power of the lockups delegated to it.
+    // The (delegation, lockup) pairs are passed as remaining accounts.
+    pub fn vote_delegated<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VoteDelegated<'info>>,
+        args: VoteDelegatedArgs,
+    ) -> Result<()> {
+        ins_v1::vote_delegated::handle(ctx, args)
+    }
+
+    // VoteWithReceipt will let the receipt holder vote with the lockup's voting power.
+    pub fn vote_with_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteWithReceipt<'info>>,
+        args: VoteWithReceiptArgs,
+    ) -> Result<()> {
+        ins_v1::vote_with_receipt::handle(ctx, args)
+    }
+
     // Init a 2FA cosigner-based distribution
     pub fn init_distribution<'info>(
         ctx: Context<'_, '_, '_, 'info, InitDistribution<'info>>,
@@ -112,11 +372,24 @@ pub mod vetoken {
 
 #[macro_export]
 macro_rules! lockup_seeds {
-    ( $ns:expr, $owner:expr, $bump:expr ) => {
+    ( $ns:expr, $owner:expr, $index:expr, $bump:expr ) => {
         &[
             b"lockup".as_ref(),
             $ns.key().as_ref(),
             $owner.key.as_ref(),
+            $crate::states::Lockup::index_seed($index).as_ref(),
+            &[$bump],
+        ]
+    };
+}
+
+#[macro_export]
+macro_rules! namespace_seeds {
+    ( $token_mint:expr, $deployer:expr, $bump:expr ) => {
+        &[
+            b"namespace".as_ref(),
+            $token_mint.key().as_ref(),
+            $deployer.as_ref(),
             &[$bump],
         ]
     };
@@ -135,3 +408,10 @@ macro_rules! distribution_seeds {
         ]
     };
 }
+
+#[macro_export]
+macro_rules! receipt_authority_seeds {
+    ( $ns_key:expr, $bump:expr ) => {
+        &[b"receipt_authority".as_ref(), $ns_key.as_ref(), &[$bump]]
+    };
+}
@@ -1,7 +1,36 @@
+use crate::errors::CustomError;
 use anchor_lang::{prelude::*, AnchorDeserialize};
-use std::convert::TryInto;
+use std::{
+    cmp::{max, min},
+    convert::TryInto,
+};
 
 const MAX_VOTING_CHOICES: usize = 6;
+pub const MAX_CHOICE_LABEL_LEN: usize = 16;
+pub const MAX_VOTE_LOCKUPS: usize = 8; // max number of lockups counted in a single vote
+
+// Voting power modes of the namespace
+pub const VOTING_POWER_MODE_LOCKUP_DURATION: u8 = 0; // based on end_ts - effective_start_ts, flat until expiry
+pub const VOTING_POWER_MODE_LINEAR_DECAY: u8 = 1; // based on end_ts - now, decays linearly to 0 at expiry (veCRV style)
+
+pub const MAX_VOTING_CURVE_POINTS: usize = 8;
+pub const MAX_LOCKUP_CHECKPOINTS: usize = 3;
+
+// Proposal statuses, see Proposal::current_status for the status as of now
+pub const PROPOSAL_STATUS_DRAFT: u8 = 0;
+pub const PROPOSAL_STATUS_ACTIVE: u8 = 1;
+pub const PROPOSAL_STATUS_PASSED: u8 = 2;
+pub const PROPOSAL_STATUS_FAILED: u8 = 3;
+pub const PROPOSAL_STATUS_QUORUM_NOT_MET: u8 = 4;
+pub const PROPOSAL_STATUS_CANCELLED: u8 = 5; // by review council before start_ts
+pub const PROPOSAL_STATUS_VETOED: u8 = 6; // by security council before finalization
+
+// Breakpoint of the piecewise voting power curve, see Namespace::voting_curve_pct
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct VotingCurvePoint {
+    pub duration: i64,
+    pub multiplier_pct: u16, // in percent
+}
 
 #[account]
 #[derive(Copy, InitSpace)]
@@ -27,7 +56,19 @@ pub struct Namespace {
     pub lockup_amount: u64,
     pub proposal_nonce: u32,
 
-    pub _padding: [u8; 240],
+    // Config added after the initial release, carved out of the padding
+    pub lockup_early_unlock_penalty_pct: u16, // max penalty at max saturation, 0 disables early unstake
+    pub lockup_voting_power_mode: u8,
+    pub lockup_unstake_cooldown: i64, // in seconds after request_unstake, 0 disables the cooldown
+    pub emergency_unlock: bool, // set by security council, everyone can unstake and staking/voting are stopped
+
+    // Piecewise voting power curve, the first lockup_voting_curve_len points are used.
+    // 0 keeps the linear ramp from lockup_min_duration to lockup_max_saturation. Only for
+    // VOTING_POWER_MODE_LOCKUP_DURATION, the linear decay always decays to 0 at expiry.
+    pub lockup_voting_curve_len: u8,
+    pub lockup_voting_curve: [VotingCurvePoint; MAX_VOTING_CURVE_POINTS],
+
+    pub _padding: [u8; 147],
 }
 
 impl Namespace {
@@ -51,13 +92,105 @@ impl Namespace {
             && self.proposal_min_voting_power_for_quorum > 0
             && self.proposal_min_pass_pct > 0
             && self.proposal_min_pass_pct <= 100
+            && self.lockup_early_unlock_penalty_pct <= 100
+            && self.lockup_unstake_cooldown >= 0
+            && (self.lockup_voting_power_mode == VOTING_POWER_MODE_LOCKUP_DURATION
+                || self.lockup_voting_power_mode == VOTING_POWER_MODE_LINEAR_DECAY)
+            && self.valid_voting_curve()
+    }
+
+    pub fn voting_curve(&self) -> &[VotingCurvePoint] {
+        &self.lockup_voting_curve[..self.lockup_voting_curve_len as usize]
+    }
+
+    // The curve needs increasing durations and non-decreasing multipliers starting from 100%,
+    // and it ends at lockup_default_target_voting_pct, which the lockups are scaled against.
+    // Its first multiplier would keep the linear decay from going below 1x, so it's only
+    // allowed in the lockup duration mode.
+    fn valid_voting_curve(&self) -> bool {
+        if self.lockup_voting_curve_len as usize > MAX_VOTING_CURVE_POINTS {
+            return false;
+        }
+        let points = self.voting_curve();
+        match (points.first(), points.last()) {
+            (Some(first), Some(last)) => {
+                self.lockup_voting_power_mode == VOTING_POWER_MODE_LOCKUP_DURATION
+                    && first.duration >= 0
+                    && first.multiplier_pct >= 100
+                    && last.multiplier_pct == self.lockup_default_target_voting_pct
+                    && points.windows(2).all(|w| {
+                        w[0].duration < w[1].duration && w[0].multiplier_pct <= w[1].multiplier_pct
+                    })
+            }
+            _ => true,
+        }
+    }
+
+    // Multiplier in percent for the duration on the voting curve. The boost above 100% is
+    // scaled from the curve's last multiplier to the lockup's target_voting_pct, so that
+    // lockups with the default target_voting_pct follow the curve exactly.
+    pub fn voting_curve_pct(&self, duration: u128, target_voting_pct: u16) -> u128 {
+        let points = self.voting_curve();
+        let last = points.last().expect("voting curve should not be empty");
+
+        let mut curve_pct = last.multiplier_pct as u128;
+        for (i, point) in points.iter().enumerate() {
+            if duration > point.duration as u128 {
+                continue;
+            }
+            curve_pct = match i {
+                0 => point.multiplier_pct as u128,
+                _ => {
+                    let prev = &points[i - 1];
+                    prev.multiplier_pct as u128
+                        + (point.multiplier_pct - prev.multiplier_pct) as u128
+                            * (duration - prev.duration as u128)
+                            / (point.duration - prev.duration) as u128
+                }
+            };
+            break;
+        }
+
+        if last.multiplier_pct <= 100 {
+            return curve_pct;
+        }
+        100 + (curve_pct - 100) * (target_voting_pct as u128).saturating_sub(100)
+            / (last.multiplier_pct - 100) as u128
+    }
+}
+
+// State of the lockup that took effect at ts, see Lockup::voting_power_at
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct LockupCheckpoint {
+    pub ts: i64,
+    pub amount: u64,
+    pub start_ts: i64, // effective_start_ts
+    pub end_ts: i64,
+    pub perpetual: bool,
+    pub target_voting_pct: u16,
+    pub vesting_amount: u64, // the vesting schedule itself never changes
+}
+
+impl LockupCheckpoint {
+    // The voting power grows with the amount, the duration, the multiplier and the vested
+    // amount, so this state has at most the voting power of both checkpoints at any time
+    fn lower_bound(&self, other: &LockupCheckpoint) -> LockupCheckpoint {
+        LockupCheckpoint {
+            ts: min(self.ts, other.ts),
+            amount: min(self.amount, other.amount),
+            start_ts: max(self.start_ts, other.start_ts),
+            end_ts: min(self.end_ts, other.end_ts),
+            perpetual: self.perpetual && other.perpetual,
+            target_voting_pct: min(self.target_voting_pct, other.target_voting_pct),
+            vesting_amount: max(self.vesting_amount, other.vesting_amount),
+        }
     }
 }
 
 #[account]
 #[derive(Copy, InitSpace)]
 pub struct Lockup {
-    // Seeds: [b"lockup", ns.key().as_ref(), owner.key().as_ref()]
+    // Seeds: [b"lockup", ns.key().as_ref(), owner.key().as_ref(), Lockup::index_seed(index).as_ref()]
     pub ns: Pubkey,
     pub owner: Pubkey,
     pub amount: u64,
@@ -71,10 +204,47 @@ pub struct Lockup {
     // New field to track weighted start for voting/reward power
     // Added after existing fields for backward compatibility
     pub weighted_start_ts: i64,
-    
+
+    // The latest end_ts of the proposals this lockup has voted on, the lockup
+    // cannot be unstaked early or change hands until then, otherwise its tokens
+    // could vote twice
+    pub voted_proposal_end_ts: i64,
+
+    // Index of the lockup for the owner, 0 is the original owner-seeded lockup
+    pub index: u32,
+
+    // Perpetual lockups always count as if end_ts = now + lockup_max_saturation,
+    // the countdown starts when the owner turns it off
+    pub perpetual: bool,
+
+    // Delegated lockups can only vote through the delegate, see Delegation
+    pub delegated: bool,
+
+    // When the owner requested to unstake the expired lockup, 0 if not requested,
+    // see Namespace::lockup_unstake_cooldown
+    pub unstake_requested_ts: i64,
+
+    // Vesting schedule of the council grants, see stake_to_vesting. vesting_amount
+    // is released linearly from vesting_start_ts to vesting_end_ts after the cliff,
+    // and the unvested part can be clawed back. 0 for normal lockups.
+    pub vesting_amount: u64,
+    pub vesting_start_ts: i64,
+    pub vesting_cliff_ts: i64,
+    pub vesting_end_ts: i64,
+
+    // Token-2022 NFT representing the position, see mint_lockup_receipt. Once minted,
+    // the holder of the NFT acts as the owner, Pubkey::default() if there is none.
+    pub receipt_mint: Pubkey,
+
+    // Checkpoints sorted by ts, written whenever the voting power inputs change, so that
+    // votes use the voting power as of the proposal start. The first checkpoint_count are
+    // used, see write_checkpoint.
+    pub checkpoints: [LockupCheckpoint; MAX_LOCKUP_CHECKPOINTS],
+    pub checkpoint_count: u32,
+
     // Padding at the end for future field additions
-    // Reduced from 240 to 232 bytes to accommodate weighted_start_ts
-    pub _padding: [u8; 232],
+    // Reduced from 240 to 13 bytes to accommodate the fields above
+    pub _padding: [u8; 13],
 }
 
 impl Lockup {
@@ -89,6 +259,99 @@ impl Lockup {
         + 2   // target_voting_pct
         + 240; // legacy padding (total: 340 bytes)
 
+    pub fn has_receipt(&self) -> bool {
+        self.receipt_mint != Pubkey::default()
+    }
+
+    // The lockup moved to a new owner keeps the amount, the timestamps and the multipliers,
+    // only the owner and the index of its seeds change
+    pub fn transferred(&self, new_owner: Pubkey, new_index: u32) -> Lockup {
+        let mut new_lockup = *self;
+        new_lockup.owner = new_owner;
+        new_lockup.index = new_index;
+        new_lockup
+    }
+
+    pub fn has_active_votes(&self, ns: &Namespace) -> bool {
+        self.voted_proposal_end_ts >= ns.now()
+    }
+
+    // A delegated lockup cannot be unstaked, except in the emergency unlock mode where the
+    // unstake closes the delegation together with the lockup. Revoking the delegation instead
+    // would let the owner vote again with the voting power the delegate already counted.
+    pub fn can_unstake_delegated(&self, ns: &Namespace, closes_delegation: bool) -> bool {
+        !self.delegated || (ns.emergency_unlock && closes_delegation)
+    }
+
+    pub fn effective_end_ts(&self, ns: &Namespace) -> i64 {
+        if self.perpetual {
+            return ns
+                .now()
+                .checked_add(ns.lockup_max_saturation as i64)
+                .expect("should not overflow");
+        }
+        self.end_ts
+    }
+
+    pub fn unstake_cooldown_ended(&self, ns: &Namespace) -> bool {
+        if ns.lockup_unstake_cooldown == 0 {
+            return true;
+        }
+        self.unstake_requested_ts != 0
+            && ns.now()
+                >= self
+                    .unstake_requested_ts
+                    .checked_add(ns.lockup_unstake_cooldown)
+                    .expect("should not overflow")
+    }
+
+    pub fn unvested_amount(&self, ns: &Namespace) -> u64 {
+        let now = ns.now();
+        if self.vesting_amount == 0 || now >= self.vesting_end_ts {
+            return 0;
+        }
+        if now < self.vesting_cliff_ts || self.vesting_end_ts <= self.vesting_start_ts {
+            return self.vesting_amount;
+        }
+
+        let ret = self.vesting_amount as u128 * (self.vesting_end_ts - now) as u128
+            / (self.vesting_end_ts - self.vesting_start_ts) as u128;
+        ret.try_into().expect("should not overflow")
+    }
+
+    pub fn vested_amount(&self, ns: &Namespace) -> u64 {
+        self.amount.saturating_sub(self.unvested_amount(ns))
+    }
+
+    // The unvested tokens can be partly gone if the lockup was unstaked from before
+    pub fn clawback_amount(&self, ns: &Namespace) -> u64 {
+        min(self.unvested_amount(ns), self.amount)
+    }
+
+    // If the remaining balance of a partial unstake would fall below the namespace
+    // minimum, fall back to a full unstake and withdraw the whole amount. A remaining
+    // balance that is still vesting can't be withdrawn, so the partial unstake is rejected
+    pub fn partial_unstake_amount(&self, ns: &Namespace, amount: u64) -> Result<u64> {
+        let remaining = self.amount.saturating_sub(amount);
+        if remaining >= ns.lockup_min_amount {
+            return Ok(amount);
+        }
+        if self.vested_amount(ns) < self.amount {
+            return Err(CustomError::LockupVesting.into());
+        }
+        Ok(self.amount)
+    }
+
+    // The original lockup (index 0) is seeded by [b"lockup", ns, owner] only.
+    // An empty seed doesn't change the derived address, so the same seeds
+    // work for both the original lockup and the index-seeded lockups.
+    pub fn index_seed(index: u32) -> Vec<u8> {
+        if index == 0 {
+            return vec![];
+        }
+        index.to_le_bytes().to_vec()
+    }
+
     pub fn min_end_ts(&self, ns: &Namespace) -> i64 {
         ns.now()
             .checked_add(ns.lockup_min_duration)
@@ -96,12 +359,16 @@ impl Lockup {
     }
 
     pub fn valid(&self, ns: &Namespace) -> bool {
+        let end_ts = self.effective_end_ts(ns);
         self.amount >= ns.lockup_min_amount
             && self.start_ts >= 0
-            && (self.end_ts >= self.min_end_ts(ns) || self.end_ts == 0)
-            && (self.end_ts >= self.start_ts || self.end_ts == 0)
-            && self.target_voting_pct >= 100
-            && self.target_voting_pct <= 2500 // max 25x
+            && (end_ts >= self.min_end_ts(ns) || end_ts == 0)
+            && (end_ts >= self.start_ts || end_ts == 0)
+            && self.valid_target_pcts()
+    }
+
+    pub fn valid_target_pcts(&self) -> bool {
+        self.target_voting_pct >= 100 && self.target_voting_pct <= 2500 // max 25x
     }
 
     pub fn  effective_start_ts(&self) -> i64 {
@@ -119,6 +386,342 @@ impl Lockup {
         }
     }
 
+    /*
+     * Deposit amount into the lockup and set the end_ts (capped by lockup_max_saturation).
+     * The first deposit starts the lockup. Additional deposits conserve the time-weighted
+     * area of the lockup, so tokens added close to end_ts only get a small multiplier,
+     * and the end_ts cannot be shortened.
+     * Perpetual lockups ignore the requested end_ts and stay at now + lockup_max_saturation.
+     */
+    pub fn deposit(&mut self, ns: &Namespace, amount: u64, end_ts: i64) -> Result<()> {
+        let now = ns.now();
+
+        if self.amount == 0 {
+            self.start_ts = now;
+            self.weighted_start_ts = now;
+            self.end_ts = min(
+                end_ts,
+                self.start_ts
+                    .checked_add(ns.lockup_max_saturation as i64)
+                    .expect("should not overflow"),
+            );
+            self.amount = amount;
+            return Ok(());
+        }
+
+        // Additional stake: conserve time-weighted area and forbid shortening end_ts
+        require!(end_ts > now, CustomError::InvalidTimestamp);
+
+        let old_amount = self.amount as u128;
+        let delta_amount = amount as u128;
+        let new_amount = old_amount
+            .checked_add(delta_amount)
+            .expect("should not overflow");
+
+        let (end_ts, max_end_ts) = if self.perpetual {
+            self.end_ts = self.effective_end_ts(ns);
+            (self.end_ts, self.end_ts)
+        } else {
+            (
+                end_ts,
+                self.start_ts
+                    .checked_add(ns.lockup_max_saturation as i64)
+                    .expect("should not overflow"),
+            )
+        };
+        let capped_end = min(end_ts, max_end_ts);
+
+        // Special case: if old end_ts was 0 (unset), treat as first-time setting
+        if self.end_ts == 0 {
+            self.end_ts = capped_end;
+            self.weighted_start_ts = now;
+            self.amount = new_amount as u64;
+            return Ok(());
+        }
+
+        // Normal case: old lockup has valid end_ts, use weighted area conservation
+        require!(self.end_ts > self.start_ts, CustomError::InvalidTimestamp);
+        require!(end_ts >= self.end_ts, CustomError::InvalidTimestamp);
+
+        // The existing amount keeps its effective start and gains the area of the
+        // extension to capped_end, the added amount starts now.
+        let new_weighted_start = Self::conserved_weighted_start_ts(
+            capped_end,
+            &[(self.amount, self.effective_start_ts()), (amount, now)],
+        )?;
+
+        self.amount = new_amount as u64;
+        self.end_ts = capped_end;
+        self.weighted_start_ts = new_weighted_start;
+
+        Ok(())
+    }
+
+    // Deposit by someone other than the owner (stake_to, stake_for). A new lockup starts with the
+    // namespace defaults and end_ts, an existing one keeps its end_ts and multipliers. An existing
+    // lockup staked with end_ts 0 has no end_ts to keep, so it takes end_ts like a new one.
+    pub fn deposit_on_behalf(&mut self, ns: &Namespace, amount: u64, end_ts: i64) -> Result<()> {
+        let end_ts = if self.amount == 0 {
+            self.target_rewards_pct = ns.lockup_default_target_rewards_pct;
+            self.target_voting_pct = ns.lockup_default_target_voting_pct;
+            end_ts
+        } else if self.end_ts == 0 {
+            require!(end_ts >= self.min_end_ts(ns), CustomError::InvalidTimestamp);
+            end_ts
+        } else {
+            self.end_ts
+        };
+        self.deposit(ns, amount, end_ts)
+    }
+
+    /*
+     * Deposit of any payer into the beneficiary's lockup (stake_for). Every deposit needs
+     * lockup_min_amount, so the beneficiary can't be spammed with dust, and an existing lockup
+     * has to be active. A lockup staked with end_ts 0 is rejected, giving it an end_ts would
+     * lock the beneficiary's tokens.
+     */
+    pub fn stake_for(
+        &mut self,
+        ns: &Namespace,
+        ns_key: Pubkey,
+        beneficiary: Pubkey,
+        amount: u64,
+        end_ts: i64,
+    ) -> Result<()> {
+        require!(!ns.emergency_unlock, CustomError::EmergencyUnlocked);
+        require!(
+            amount >= ns.lockup_min_amount,
+            CustomError::InvalidLockupAmount
+        );
+        if self.amount == 0 {
+            require!(end_ts >= self.min_end_ts(ns), CustomError::InvalidTimestamp);
+        } else {
+            require!(self.end_ts != 0, CustomError::LockupWithoutEndTs);
+            require!(
+                self.effective_end_ts(ns) > ns.now(),
+                CustomError::InvalidTimestamp
+            );
+        }
+
+        self.deposit_on_behalf(ns, amount, end_ts)?;
+        self.ns = ns_key;
+        self.owner = beneficiary;
+        Ok(())
+    }
+
+    /*
+     * Weighted start_ts of a lockup ending at end_ts that holds all the (amount, effective start_ts)
+     * parts. The time-weighted area amount * (end_ts - start_ts) of each part is conserved, i.e. each
+     * part keeps its own effective start and is extended to end_ts. Shared by deposit and merge.
+     */
+    fn conserved_weighted_start_ts(end_ts: i64, parts: &[(u64, i64)]) -> Result<i64> {
+        let mut total_amount: u128 = 0;
+        let mut total_tw: u128 = 0;
+        for &(amount, start_ts) in parts {
+            let duration = (end_ts as i128)
+                .checked_sub(start_ts as i128)
+                .expect("should not overflow");
+
+            // Guard against negative or excessively large duration
+            require!(
+                duration >= 0 && duration <= i64::MAX as i128,
+                CustomError::InvalidTimestamp
+            );
+
+            total_amount = total_amount
+                .checked_add(amount as u128)
+                .expect("should not overflow");
+            total_tw = (amount as u128)
+                .checked_mul(duration as u128)
+                .and_then(|tw| tw.checked_add(total_tw))
+                .expect("should not overflow");
+        }
+        require!(total_amount > 0, CustomError::InvalidLockupAmount);
+
+        let weighted_start_ts = (end_ts as i128)
+            .checked_sub((total_tw / total_amount) as i128)
+            .expect("should not underflow");
+        Ok(weighted_start_ts as i64)
+    }
+
+    // Relock restarts an expired lockup in place, as if all of its tokens (plus the
+    // optional additional amount) were freshly staked now.
+    pub fn relock(&mut self, ns: &Namespace, amount: u64, end_ts: i64) -> Result<()> {
+        let now = ns.now();
+        require!(
+            self.effective_end_ts(ns) <= now,
+            CustomError::InvalidTimestamp
+        );
+
+        self.start_ts = now;
+        self.weighted_start_ts = now;
+        self.unstake_requested_ts = 0;
+        self.end_ts = min(
+            end_ts,
+            now.checked_add(ns.lockup_max_saturation as i64)
+                .expect("should not overflow"),
+        );
+        self.amount = self
+            .amount
+            .checked_add(amount)
+            .expect("should not overflow");
+
+        Ok(())
+    }
+
+    // Carve amount out of this lockup into a new one at new_index, both keep the same
+    // end_ts, effective start and multipliers
+    pub fn split(&mut self, amount: u64, new_index: u32) -> Lockup {
+        let mut new_lockup = *self;
+        new_lockup.amount = amount;
+        new_lockup.index = new_index;
+        self.amount = self
+            .amount
+            .checked_sub(amount)
+            .expect("underflow in reducing lockup.amount");
+        new_lockup
+    }
+
+    /*
+     * Merge the other lockup into this one with the later end_ts of the two.
+     * Same as deposit, the time-weighted area of both lockups is conserved, i.e.
+     * each amount keeps its own effective start and is extended to the new end_ts.
+     */
+    pub fn merge(&mut self, ns: &Namespace, other: &Lockup) -> Result<()> {
+        let now = ns.now();
+        let end_ts = max(self.effective_end_ts(ns), other.effective_end_ts(ns));
+        require!(
+            self.effective_end_ts(ns) > now && other.effective_end_ts(ns) > now,
+            CustomError::InvalidTimestamp
+        );
+
+        let new_amount = self
+            .amount
+            .checked_add(other.amount)
+            .expect("should not overflow");
+        let new_weighted_start = Self::conserved_weighted_start_ts(
+            end_ts,
+            &[
+                (self.amount, self.effective_start_ts()),
+                (other.amount, other.effective_start_ts()),
+            ],
+        )?;
+
+        // the later start_ts keeps end_ts within the lockup_max_saturation cap of future deposits
+        self.start_ts = max(self.start_ts, other.start_ts);
+        self.end_ts = end_ts;
+        self.weighted_start_ts = new_weighted_start;
+        self.amount = new_amount;
+        self.voted_proposal_end_ts = max(self.voted_proposal_end_ts, other.voted_proposal_end_ts);
+        self.perpetual = self.perpetual || other.perpetual;
+
+        Ok(())
+    }
+
+    fn checkpoint(&self, ts: i64) -> LockupCheckpoint {
+        LockupCheckpoint {
+            ts,
+            amount: self.amount,
+            start_ts: self.effective_start_ts(),
+            end_ts: self.end_ts,
+            perpetual: self.perpetual,
+            target_voting_pct: self.target_voting_pct,
+            vesting_amount: self.vesting_amount,
+        }
+    }
+
+    /*
+     * Lockups created before the checkpoints have none, and vote with their current state.
+     * Record that state as of ts 0 before the lockup changes for the first time, otherwise
+     * the change would also apply to the proposals that have already started.
+     */
+    pub fn seed_checkpoint(&mut self) {
+        if self.checkpoint_count == 0 && self.amount > 0 {
+            self.checkpoints[0] = self.checkpoint(0);
+            self.checkpoint_count = 1;
+        }
+    }
+
+    /*
+     * Record the current state of the lockup as of now. Once all the slots are used, the
+     * adjacent pair of older checkpoints that loses the least voting power is merged into
+     * their lower bound. The history can only under-count the voting power, and many small
+     * changes after a proposal start, e.g. dust stake_for deposits, barely change the
+     * voting power that the lockup had at the proposal start.
+     */
+    pub fn write_checkpoint(&mut self, ns: &Namespace) {
+        let checkpoint = self.checkpoint(ns.now());
+        let mut len = min(self.checkpoint_count as usize, MAX_LOCKUP_CHECKPOINTS);
+
+        if len > 0 && self.checkpoints[len - 1].ts == checkpoint.ts {
+            self.checkpoints[len - 1] = checkpoint; // multiple changes in the same block
+            return;
+        }
+
+        if len == MAX_LOCKUP_CHECKPOINTS {
+            let i = (1..len)
+                .min_by_key(|&i| self.checkpoint_merge_loss(ns, i))
+                .expect("there are at least two checkpoints");
+            self.checkpoints[i - 1] = self.checkpoints[i - 1].lower_bound(&self.checkpoints[i]);
+            self.checkpoints.copy_within(i + 1..len, i);
+            len -= 1;
+        }
+
+        self.checkpoints[len] = checkpoint;
+        self.checkpoint_count = (len + 1) as u32;
+    }
+
+    // Voting power lost as of checkpoints[i].ts by merging checkpoints[i - 1] and checkpoints[i]
+    fn checkpoint_merge_loss(&self, ns: &Namespace, i: usize) -> u64 {
+        let ts = self.checkpoints[i].ts;
+        let merged = self.checkpoints[i - 1].lower_bound(&self.checkpoints[i]);
+        let merged_voting_power = self.checkpoint_voting_power(ns, &merged, ts);
+        [self.checkpoints[i - 1], self.checkpoints[i]]
+            .iter()
+            .map(|c| {
+                self.checkpoint_voting_power(ns, c, ts)
+                    .saturating_sub(merged_voting_power)
+            })
+            .sum()
+    }
+
+    fn checkpoint_voting_power(
+        &self,
+        ns: &Namespace,
+        checkpoint: &LockupCheckpoint,
+        ts: i64,
+    ) -> u64 {
+        let mut ns_at = *ns;
+        ns_at.override_now = ts;
+
+        let mut lockup_at = *self;
+        lockup_at.amount = checkpoint.amount;
+        lockup_at.start_ts = checkpoint.start_ts;
+        lockup_at.weighted_start_ts = checkpoint.start_ts;
+        lockup_at.end_ts = checkpoint.end_ts;
+        lockup_at.perpetual = checkpoint.perpetual;
+        lockup_at.target_voting_pct = checkpoint.target_voting_pct;
+        lockup_at.vesting_amount = checkpoint.vesting_amount;
+        lockup_at.voting_power(&ns_at)
+    }
+
+    // Voting power as of ts, evaluated with the latest checkpoint at or before ts. It's 0
+    // if the lockup didn't exist at ts.
+    pub fn voting_power_at(&self, ns: &Namespace, ts: i64) -> u64 {
+        // lockups created before the checkpoints are evaluated with their current state
+        if self.checkpoint_count == 0 {
+            let mut ns_at = *ns;
+            ns_at.override_now = ts;
+            return self.voting_power(&ns_at);
+        }
+
+        let len = min(self.checkpoint_count as usize, MAX_LOCKUP_CHECKPOINTS);
+        match self.checkpoints[..len].iter().rev().find(|c| c.ts <= ts) {
+            Some(checkpoint) => self.checkpoint_voting_power(ns, checkpoint, ts),
+            None => 0,
+        }
+    }
+
     /*
      * Voting power is based on the target_voting_pct
      * Summary:
@@ -128,6 +731,12 @@ impl Lockup {
      * 4. Handle maximum saturation case (return max voting power)
      * 5. For durations between min and max, calculate a linear increase in voting power
      *
+     * The duration depends on the namespace's lockup_voting_power_mode:
+     * - VOTING_POWER_MODE_LOCKUP_DURATION: EndTs - EffectiveStartTs, the voting power
+     *   stays flat until the lockup expires.
+     * - VOTING_POWER_MODE_LINEAR_DECAY: EndTs - Now, veCRV style. The max voting power at
+     *   MaxTime of remaining time decays linearly down to 0 at EndTs, MinTime doesn't apply.
+     *
      *                  Voting Power
      *                   ^
      *                   |
@@ -139,29 +748,44 @@ impl Lockup {
      *                   |     /
      *             100%  |    /
      *                   | ---
-     *                   +---------------------> Lockup Time (EndTs - StartTs)
+     *                   +---------------------> Lockup Time (Duration)
      *                     MinTime   MaxTime
      */
     pub fn voting_power(&self, ns: &Namespace) -> u64 {
         let now = ns.now();
+        let end_ts = self.effective_end_ts(ns);
 
-        if now >= self.end_ts {
+        if now >= end_ts || self.unstake_requested_ts != 0 {
             return 0;
         }
-        if self.end_ts <= self.start_ts {
+        if end_ts <= self.start_ts {
             return 0;
         }
 
-        let duration = (self.end_ts - self.effective_start_ts()) as u128;
-        let max_voting_power = (self.amount as u128 * self.target_voting_pct as u128) / 100;
+        let duration = match ns.lockup_voting_power_mode {
+            VOTING_POWER_MODE_LINEAR_DECAY => (end_ts - now) as u128,
+            _ => (end_ts - self.effective_start_ts()) as u128,
+        };
+        // only the vested tokens count, the unvested ones can still be clawed back
+        let amount = self.vested_amount(ns);
+        let max_voting_power = (amount as u128 * self.target_voting_pct as u128) / 100;
+        if ns.lockup_voting_power_mode == VOTING_POWER_MODE_LINEAR_DECAY {
+            let ret = max_voting_power * min(duration, ns.lockup_max_saturation as u128)
+                / ns.lockup_max_saturation as u128;
+            return ret.try_into().expect("should not overflow");
+        }
+        if ns.lockup_voting_curve_len > 0 {
+            let ret = amount as u128 * ns.voting_curve_pct(duration, self.target_voting_pct) / 100;
+            return ret.try_into().expect("should not overflow");
+        }
         if duration <= ns.lockup_min_duration as u128 {
-            return self.amount; // minimal 100% of the amount
+            return amount; // minimal 100% of the amount
         }
         if duration >= ns.lockup_max_saturation as u128 {
             return max_voting_power.try_into().expect("should not overflow");
         }
 
-        let amount = self.amount as u128;
+        let amount = amount as u128;
 
         let ret = amount
             + (max_voting_power - amount) * (duration - ns.lockup_min_duration as u128)
@@ -170,6 +794,26 @@ impl Lockup {
         ret.try_into().expect("should not overflow")
     }
 
+    /*
+     * Early unlock penalty scales linearly with the remaining lockup time
+     * and reaches lockup_early_unlock_penalty_pct of the amount when the
+     * remaining time is at (or above) lockup_max_saturation.
+     */
+    pub fn early_unlock_penalty(&self, ns: &Namespace) -> u64 {
+        let now = ns.now();
+        let end_ts = self.effective_end_ts(ns);
+        if now >= end_ts {
+            return 0;
+        }
+
+        let remaining = min((end_ts - now) as u128, ns.lockup_max_saturation as u128);
+        let ret = self.amount as u128 * ns.lockup_early_unlock_penalty_pct as u128 * remaining
+            / 100
+            / ns.lockup_max_saturation as u128;
+
+        ret.try_into().expect("should not overflow")
+    }
+
     // rewards_power is the voting power that can receive rewards based on the target_rewards_pct
     // it's not used in this program, but will be consumed by other programs
     #[allow(dead_code)]
@@ -192,37 +836,179 @@ pub struct Proposal {
 
     pub start_ts: i64,
     pub end_ts: i64,
-    pub status: u8, // not used at the moment, but a placeholder for future use
+    // PROPOSAL_STATUS_*, Draft until the first vote persists Active, then written by
+    // finalize_proposal, cancel_proposal or veto_proposal. A proposal without votes stays
+    // Draft after start_ts, so read the status with current_status.
+    pub status: u8,
     pub voting_power_choices: [u64; MAX_VOTING_CHOICES], // cumulative voting power for each choice
 
     #[max_len(256)]
     pub uri: String,
 
-    pub _padding: [u8; 240],
+    // Added after the initial release, carved out of the padding
+    pub winning_choice: u8, // the choice with the most voting power, set by finalize_proposal
+    pub num_choices: u8, // 0 for the older proposals, which accept all MAX_VOTING_CHOICES choices
+    pub choice_labels: [[u8; MAX_CHOICE_LABEL_LEN]; MAX_VOTING_CHOICES], // optional utf-8 labels, zero padded
+    pub min_voting_power_for_quorum: u64, // snapshot of ns.proposal_min_voting_power_for_quorum at init_proposal, 0 for the older proposals
+    pub min_pass_pct: u16, // snapshot of ns.proposal_min_pass_pct at init_proposal, 0 for the older proposals
+
+    pub _padding: [u8; 132],
 }
 
 impl Proposal {
     pub fn valid(&self) -> bool {
-        self.uri.len() <= 255 && self.start_ts < self.end_ts
+        self.uri.len() <= 255
+            && self.start_ts < self.end_ts
+            && (self.num_choices as usize) <= MAX_VOTING_CHOICES
+    }
+
+    pub fn num_choices(&self) -> usize {
+        if self.num_choices == 0 {
+            return MAX_VOTING_CHOICES;
+        }
+        self.num_choices as usize
+    }
+
+    // Labels are optional, but there can't be more labels than choices and each label
+    // needs to fit in MAX_CHOICE_LABEL_LEN bytes.
+    pub fn set_choice_labels(&mut self, choice_labels: &[String]) -> bool {
+        if choice_labels.len() > self.num_choices() {
+            return false;
+        }
+        self.choice_labels = [[0; MAX_CHOICE_LABEL_LEN]; MAX_VOTING_CHOICES];
+        for (i, label) in choice_labels.iter().enumerate() {
+            if label.len() > MAX_CHOICE_LABEL_LEN {
+                return false;
+            }
+            self.choice_labels[i][..label.len()].copy_from_slice(label.as_bytes());
+        }
+        true
+    }
+
+    // The choice and the non-zero choice weights need to be within num_choices
+    pub fn valid_choice(&self, choice: u8, choice_weights_pct: &[u8]) -> bool {
+        (choice as usize) < self.num_choices()
+            && choice_weights_pct
+                .iter()
+                .skip(self.num_choices())
+                .all(|&w| w == 0)
     }
 
     pub fn can_update(&self) -> bool {
-        if self.total_voting_power() > 0 {
+        if self.total_voting_power() > 0 || self.is_finalized() {
             return false;
         }
         true
     }
 
-    pub fn cast_vote(&mut self, choice: u8, voting_power: u64) {
-        match choice {
-            0..=5 => {
-                self.voting_power_choices[choice as usize] = self.voting_power_choices
-                    [choice as usize]
-                    .checked_add(voting_power)
-                    .expect("should not overflow")
+    pub fn is_finalized(&self) -> bool {
+        matches!(
+            self.status,
+            PROPOSAL_STATUS_PASSED
+                | PROPOSAL_STATUS_FAILED
+                | PROPOSAL_STATUS_QUORUM_NOT_MET
+                | PROPOSAL_STATUS_CANCELLED
+                | PROPOSAL_STATUS_VETOED
+        )
+    }
+
+    pub fn current_status(&self, ns: &Namespace) -> u8 {
+        if self.is_finalized() {
+            return self.status;
+        }
+        if ns.now() < self.start_ts {
+            return PROPOSAL_STATUS_DRAFT;
+        }
+        PROPOSAL_STATUS_ACTIVE
+    }
+
+    // The first choice with the most voting power, ties go to the lower index
+    pub fn leading_choice(&self) -> u8 {
+        let mut leading_choice = 0;
+        for (i, &voting_power) in self.voting_power_choices.iter().enumerate() {
+            if voting_power > self.voting_power_choices[leading_choice] {
+                leading_choice = i;
+            }
+        }
+        leading_choice as u8
+    }
+
+    pub fn finalize(&mut self, ns: &Namespace) {
+        self.winning_choice = self.leading_choice();
+        self.status = if !self.has_quorum(ns) {
+            PROPOSAL_STATUS_QUORUM_NOT_MET
+        } else if self.has_passed(ns) {
+            PROPOSAL_STATUS_PASSED
+        } else {
+            PROPOSAL_STATUS_FAILED
+        };
+    }
+
+    pub fn cast_vote(&mut self, choice: u8, voting_power: u64) -> Result<()> {
+        if (choice as usize) >= self.num_choices() {
+            return Err(CustomError::InvalidChoice.into());
+        }
+        let voting_power_choice = &mut self.voting_power_choices[choice as usize];
+        *voting_power_choice = voting_power_choice
+            .checked_add(voting_power)
+            .expect("should not overflow");
+        Ok(())
+    }
+
+    pub fn revoke_vote(&mut self, choice: u8, voting_power: u64) -> Result<()> {
+        if (choice as usize) >= self.num_choices() {
+            return Err(CustomError::InvalidChoice.into());
+        }
+        let voting_power_choice = &mut self.voting_power_choices[choice as usize];
+        *voting_power_choice = voting_power_choice
+            .checked_sub(voting_power)
+            .expect("should not underflow");
+        Ok(())
+    }
+
+    // Splits the voting power by the choice weights, the rounding remainder goes to the
+    // first choice with the largest weight so the parts always add up to the voting power.
+    pub fn split_voting_power(
+        choice_weights_pct: &[u8; MAX_VOTING_CHOICES],
+        voting_power: u64,
+    ) -> [u64; MAX_VOTING_CHOICES] {
+        let mut parts = [0u64; MAX_VOTING_CHOICES];
+        for (i, &weight_pct) in choice_weights_pct.iter().enumerate() {
+            parts[i] = (voting_power as u128 * weight_pct as u128 / 100) as u64;
+        }
+        let remainder = voting_power - parts.iter().sum::<u64>();
+        parts[largest_weight_choice(choice_weights_pct)] += remainder;
+        parts
+    }
+
+    // Votes are only cast on active proposals, so the first one persists the Active status
+    pub fn cast_split_vote(
+        &mut self,
+        choice_weights_pct: &[u8; MAX_VOTING_CHOICES],
+        voting_power: u64,
+    ) -> Result<()> {
+        self.status = PROPOSAL_STATUS_ACTIVE;
+        let parts = Self::split_voting_power(choice_weights_pct, voting_power);
+        for (choice, &part) in parts.iter().enumerate() {
+            if part > 0 {
+                self.cast_vote(choice as u8, part)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn revoke_split_vote(
+        &mut self,
+        choice_weights_pct: &[u8; MAX_VOTING_CHOICES],
+        voting_power: u64,
+    ) -> Result<()> {
+        let parts = Self::split_voting_power(choice_weights_pct, voting_power);
+        for (choice, &part) in parts.iter().enumerate() {
+            if part > 0 {
+                self.revoke_vote(choice as u8, part)?;
             }
-            _ => panic!("Invalid choice"),
         }
+        Ok(())
     }
 
     pub fn total_voting_power(&self) -> u64 {
@@ -231,12 +1017,26 @@ impl Proposal {
         })
     }
 
-    #[allow(dead_code)]
+    // The governance parameters are snapshotted at init_proposal so that update_namespace
+    // doesn't change the rules of the running proposals, older proposals fall back to the namespace.
+    pub fn min_voting_power_for_quorum(&self, ns: &Namespace) -> u64 {
+        if self.min_voting_power_for_quorum == 0 {
+            return ns.proposal_min_voting_power_for_quorum;
+        }
+        self.min_voting_power_for_quorum
+    }
+
+    pub fn min_pass_pct(&self, ns: &Namespace) -> u16 {
+        if self.min_pass_pct == 0 {
+            return ns.proposal_min_pass_pct;
+        }
+        self.min_pass_pct
+    }
+
     pub fn has_quorum(&self, ns: &Namespace) -> bool {
-        self.total_voting_power() > ns.proposal_min_voting_power_for_quorum
+        self.total_voting_power() > self.min_voting_power_for_quorum(ns)
     }
 
-    #[allow(dead_code)]
     pub fn has_passed(&self, ns: &Namespace) -> bool {
         // Check if the proposal has quorum
         if !self.has_quorum(ns) {
@@ -248,7 +1048,7 @@ impl Proposal {
         }
         let pass_threshold = self
             .total_voting_power()
-            .checked_mul(ns.proposal_min_pass_pct as u64)
+            .checked_mul(self.min_pass_pct(ns) as u64)
             .expect("should not overflow")
             .checked_div(100)
             .expect("should not overflow");
@@ -258,8 +1058,11 @@ impl Proposal {
     }
 }
 
+// Vote records created before VoteRecordV2, the layout is kept to read the existing accounts.
+// New votes create a VoteRecordV2 at the same seeds.
 #[account]
 #[derive(Copy, InitSpace)]
+#[allow(dead_code)]
 pub struct VoteRecord {
     // Seeds: [b"vote_record", ns.key().as_ref(), owner.key().as_ref(), proposal.key().as_ref()]
     pub ns: Pubkey,
@@ -273,15 +1076,155 @@ pub struct VoteRecord {
     pub _padding: [u8; 32],
 }
 
-impl VoteRecord {
+// The delegate and the list of lockups don't fit in the padding of VoteRecord, so the vote
+// records are versioned as a new account type with its own discriminator.
+#[account]
+#[derive(Copy, InitSpace)]
+pub struct VoteRecordV2 {
+    // Seeds: [b"vote_record", ns.key().as_ref(), owner.key().as_ref(), proposal.key().as_ref()]
+    // Seeds for delegated votes: [b"delegated_vote_record", ns.key().as_ref(), delegate.key().as_ref(), proposal.key().as_ref()]
+    // Seeds for receipt votes: [b"receipt_vote_record", ns.key().as_ref(), lockup.key().as_ref(), proposal.key().as_ref()]
+    pub ns: Pubkey,
+    pub owner: Pubkey, // the signer of the vote, i.e. the lockup owner, the delegate or the receipt holder
+    pub proposal: Pubkey,
+
+    pub lockup: Pubkey,
+    pub choice: u8,
+    pub voting_power: u64,
+
+    pub delegate: Pubkey, // Pubkey::default() if the vote is not cast by a delegate
+    pub lockups: [Pubkey; MAX_VOTE_LOCKUPS], // all the lockups counted in the vote, the rest are Pubkey::default()
+    pub choice_weights_pct: [u8; MAX_VOTING_CHOICES], // allocation of the voting power over the choices
+
+    pub _padding: [u8; 64],
+}
+
+// The first choice with the largest weight, the choice of a split vote
+fn largest_weight_choice(choice_weights_pct: &[u8; MAX_VOTING_CHOICES]) -> usize {
+    let mut largest = 0;
+    for (i, &weight_pct) in choice_weights_pct.iter().enumerate() {
+        if weight_pct > choice_weights_pct[largest] {
+            largest = i;
+        }
+    }
+    largest
+}
+
+impl VoteRecordV2 {
+    // The weights need to sum up to 100, and the choice of a split vote needs to be its
+    // first choice with the largest weight, so that the choice is never ignored silently
     pub fn valid(&self) -> bool {
         (self.choice as usize) < MAX_VOTING_CHOICES
+            && self
+                .choice_weights_pct
+                .iter()
+                .map(|&weight_pct| weight_pct as u64)
+                .sum::<u64>()
+                == 100
+            && self.choice as usize == largest_weight_choice(&self.choice_weights_pct)
+    }
+
+    // Sets the allocation of the voting power. Empty weights put all the voting power on the
+    // choice, otherwise the weights are stored as they are and checked by valid.
+    pub fn set_choice_weights(&mut self, choice: u8, weights_pct: &[u8]) {
+        self.choice = choice;
+        self.choice_weights_pct = [0; MAX_VOTING_CHOICES];
+        if weights_pct.is_empty() {
+            if (choice as usize) < MAX_VOTING_CHOICES {
+                self.choice_weights_pct[choice as usize] = 100;
+            }
+            return;
+        }
+        if weights_pct.len() > MAX_VOTING_CHOICES {
+            return;
+        }
+        self.choice_weights_pct[..weights_pct.len()].copy_from_slice(weights_pct);
+    }
+
+    // Vote records written before the split votes have no weights, all of their voting
+    // power is on the choice
+    pub fn cast_choice_weights_pct(&self) -> [u8; MAX_VOTING_CHOICES] {
+        if self
+            .choice_weights_pct
+            .iter()
+            .any(|&weight_pct| weight_pct > 0)
+        {
+            return self.choice_weights_pct;
+        }
+        let mut choice_weights_pct = [0; MAX_VOTING_CHOICES];
+        choice_weights_pct[self.choice as usize] = 100;
+        choice_weights_pct
     }
 }
 
 #[account]
 #[derive(Copy, InitSpace)]
-pub struct Distribution {
+pub struct Delegation {
+    // Seeds: [b"delegation", ns.key().as_ref(), lockup.key().as_ref()]
+    pub ns: Pubkey,
+    pub lockup: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+
+    pub _padding: [u8; 240],
+}
+
+impl Delegation {
+    // vote_delegated takes the delegated lockups as remaining accounts in pairs of (delegation, lockup)
+    pub fn valid_pairs_len(len: usize) -> bool {
+        if len == 0 || len % 2 != 0 {
+            return false;
+        }
+        len / 2 <= MAX_VOTE_LOCKUPS
+    }
+
+    /*
+     * Sum of the voting power of the delegated lockups as of the proposal start. Each
+     * delegation needs to delegate its lockup to the delegate, each lockup can only be
+     * counted once and needs to outlast the proposal. Returns the voting power and the
+     * keys of the lockups.
+     */
+    pub fn delegated_voting_power(
+        ns: &Namespace,
+        ns_key: &Pubkey,
+        proposal: &Proposal,
+        delegate: &Pubkey,
+        pairs: &[(Delegation, Pubkey, Lockup)],
+    ) -> Result<(u64, Vec<Pubkey>)> {
+        let mut voting_power: u64 = 0;
+        let mut lockup_keys: Vec<Pubkey> = vec![];
+        for (delegation, lockup_key, lockup) in pairs.iter() {
+            if delegation.ns != *ns_key
+                || delegation.delegate != *delegate
+                || delegation.lockup != *lockup_key
+                || delegation.owner != lockup.owner
+            {
+                return Err(CustomError::InvalidDelegation.into());
+            }
+            if lockup_keys.contains(lockup_key) || lockup.ns != *ns_key || !lockup.delegated {
+                return Err(CustomError::InvalidLockup.into());
+            }
+            if lockup.effective_end_ts(ns) <= proposal.end_ts {
+                return Err(CustomError::InvalidTimestamp.into());
+            }
+            let lockup_voting_power = lockup.voting_power_at(ns, proposal.start_ts);
+            if lockup_voting_power == 0 {
+                return Err(CustomError::InvalidVotingPower.into());
+            }
+
+            lockup_keys.push(*lockup_key);
+            voting_power = voting_power
+                .checked_add(lockup_voting_power)
+                .expect("should not overflow");
+        }
+
+        Ok((voting_power, lockup_keys))
+    }
+}
+
+#[account]
+#[derive(Copy, InitSpace)]
+pub struct Distribution {
     // Seeds: [b"distribution", ns.key().as_ref(), uuid.key().as_ref()]
     pub ns: Pubkey,
     pub uuid: Pubkey,
@@ -308,9 +1251,84 @@ pub struct DistributionClaim {
 }
 
 #[cfg(test)]
-mod tests {
+#[allow(clippy::bool_assert_comparison, clippy::manual_range_contains)]
+pub(crate) mod tests {
     use super::*;
 
+    // Namespace, Lockup and Proposal can't derive Default because of their padding arrays,
+    // tests start from these and override what they need with ..test_ns() / ..test_lockup() / ..test_proposal()
+    pub(crate) fn test_ns() -> Namespace {
+        Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: 1,
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
+            lockup_min_amount: 1,
+            lockup_max_saturation: 86400 * 365 * 4,
+            proposal_min_voting_power_for_quorum: 10000,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 0,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        }
+    }
+
+    pub(crate) fn test_lockup() -> Lockup {
+        Lockup {
+            ns: Pubkey::new_from_array([0; 32]),
+            owner: Pubkey::new_from_array([0; 32]),
+            amount: 0,
+            start_ts: 0,
+            end_ts: 0,
+            weighted_start_ts: 0,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
+        }
+    }
+
+    pub(crate) fn test_proposal() -> Proposal {
+        Proposal {
+            ns: Pubkey::new_from_array([0; 32]),
+            nonce: 0,
+            owner: Pubkey::new_from_array([0; 32]),
+            uri: "https://123".to_owned(),
+            start_ts: 0,
+            end_ts: 0,
+            status: PROPOSAL_STATUS_DRAFT,
+            voting_power_choices: [0, 0, 0, 0, 0, 0],
+            winning_choice: 0,
+            num_choices: 0,
+            choice_labels: [[0; MAX_CHOICE_LABEL_LEN]; MAX_VOTING_CHOICES],
+            min_voting_power_for_quorum: 0,
+            min_pass_pct: 0,
+            _padding: [0; 132],
+        }
+    }
+
     #[test]
     fn test_lockup_voting_power() {
         let test_cases = vec![
@@ -332,7 +1350,13 @@ mod tests {
                     proposal_can_update_after_votes: true,
                     lockup_amount: 10000,
                     proposal_nonce: 0,
-                    _padding: [0; 240],
+                    lockup_early_unlock_penalty_pct: 0,
+                    lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+                    lockup_unstake_cooldown: 0,
+                    emergency_unlock: false,
+                    lockup_voting_curve_len: 0,
+                    lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+                    _padding: [0; 147],
                 },
                 Lockup {
                     ns: Pubkey::new_from_array([0; 32]),
@@ -343,9 +1367,22 @@ mod tests {
                     weighted_start_ts: 0,
                     target_rewards_pct: 1000,
                     target_voting_pct: 5000,
-                    _padding: [0; 232],
+                    index: 0,
+                    voted_proposal_end_ts: 0,
+                    perpetual: false,
+                    delegated: false,
+                    unstake_requested_ts: 0,
+                    vesting_amount: 0,
+                    vesting_start_ts: 0,
+                    vesting_cliff_ts: 0,
+                    vesting_end_ts: 0,
+                    receipt_mint: Pubkey::default(),
+                    checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+                    checkpoint_count: 0,
+                    _padding: [0; 13],
                 },
                 0, // end_ts expired, because override_now > end_ts
+                0,
             ),
             (
                 "Case 2",
@@ -365,7 +1402,13 @@ mod tests {
                     proposal_can_update_after_votes: true,
                     lockup_amount: 10000,
                     proposal_nonce: 0,
-                    _padding: [0; 240],
+                    lockup_early_unlock_penalty_pct: 0,
+                    lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+                    lockup_unstake_cooldown: 0,
+                    emergency_unlock: false,
+                    lockup_voting_curve_len: 0,
+                    lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+                    _padding: [0; 147],
                 },
                 Lockup {
                     ns: Pubkey::new_from_array([0; 32]),
@@ -376,9 +1419,22 @@ mod tests {
                     weighted_start_ts: 0,
                     target_rewards_pct: 100,
                     target_voting_pct: 2000,
-                    _padding: [0; 232],
+                    index: 0,
+                    voted_proposal_end_ts: 0,
+                    perpetual: false,
+                    delegated: false,
+                    unstake_requested_ts: 0,
+                    vesting_amount: 0,
+                    vesting_start_ts: 0,
+                    vesting_cliff_ts: 0,
+                    vesting_end_ts: 0,
+                    receipt_mint: Pubkey::default(),
+                    checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+                    checkpoint_count: 0,
+                    _padding: [0; 13],
                 },
                 11692,
+                1917, // 14 days remaining out of the 4 years max saturation
             ),
             // Add more test cases here...
             (
@@ -399,7 +1455,13 @@ mod tests {
                     proposal_can_update_after_votes: true,
                     lockup_amount: 10000,
                     proposal_nonce: 0,
-                    _padding: [0; 240],
+                    lockup_early_unlock_penalty_pct: 0,
+                    lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+                    lockup_unstake_cooldown: 0,
+                    emergency_unlock: false,
+                    lockup_voting_curve_len: 0,
+                    lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+                    _padding: [0; 147],
                 },
                 Lockup {
                     ns: Pubkey::new_from_array([0; 32]),
@@ -410,9 +1472,22 @@ mod tests {
                     weighted_start_ts: 0,
                     target_rewards_pct: 100,
                     target_voting_pct: 2000,
-                    _padding: [0; 232],
+                    index: 0,
+                    voted_proposal_end_ts: 0,
+                    perpetual: false,
+                    delegated: false,
+                    unstake_requested_ts: 0,
+                    vesting_amount: 0,
+                    vesting_start_ts: 0,
+                    vesting_cliff_ts: 0,
+                    vesting_end_ts: 0,
+                    receipt_mint: Pubkey::default(),
+                    checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+                    checkpoint_count: 0,
+                    _padding: [0; 13],
                 },
                 0, // 0 because of the target_rewards_pct
+                0,
             ),
             (
                 "Case 4",
@@ -432,7 +1507,13 @@ mod tests {
                     proposal_can_update_after_votes: true,
                     lockup_amount: 10000,
                     proposal_nonce: 0,
-                    _padding: [0; 240],
+                    lockup_early_unlock_penalty_pct: 0,
+                    lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+                    lockup_unstake_cooldown: 0,
+                    emergency_unlock: false,
+                    lockup_voting_curve_len: 0,
+                    lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+                    _padding: [0; 147],
                 },
                 Lockup {
                     ns: Pubkey::new_from_array([0; 32]),
@@ -443,9 +1524,22 @@ mod tests {
                     weighted_start_ts: 0,
                     target_rewards_pct: 100,
                     target_voting_pct: 2000,
-                    _padding: [0; 232],
+                    index: 0,
+                    voted_proposal_end_ts: 0,
+                    perpetual: false,
+                    delegated: false,
+                    unstake_requested_ts: 0,
+                    vesting_amount: 0,
+                    vesting_start_ts: 0,
+                    vesting_cliff_ts: 0,
+                    vesting_end_ts: 0,
+                    receipt_mint: Pubkey::default(),
+                    checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+                    checkpoint_count: 0,
+                    _padding: [0; 13],
                 },
                 10000, // because we just hit the minimal duration, thus only getting 100% of the amount
+                199997, // just below 20x of the amount, 86399 of 86400 remaining out of the max saturation
             ),
             (
                 "Case 5",
@@ -465,7 +1559,13 @@ mod tests {
                     proposal_can_update_after_votes: true,
                     lockup_amount: 10000,
                     proposal_nonce: 0,
-                    _padding: [0; 240],
+                    lockup_early_unlock_penalty_pct: 0,
+                    lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+                    lockup_unstake_cooldown: 0,
+                    emergency_unlock: false,
+                    lockup_voting_curve_len: 0,
+                    lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+                    _padding: [0; 147],
                 },
                 Lockup {
                     ns: Pubkey::new_from_array([0; 32]),
@@ -476,9 +1576,22 @@ mod tests {
                     weighted_start_ts: 0,
                     target_rewards_pct: 100,
                     target_voting_pct: 2000,
-                    _padding: [0; 232],
+                    index: 0,
+                    voted_proposal_end_ts: 0,
+                    perpetual: false,
+                    delegated: false,
+                    unstake_requested_ts: 0,
+                    vesting_amount: 0,
+                    vesting_start_ts: 0,
+                    vesting_cliff_ts: 0,
+                    vesting_end_ts: 0,
+                    receipt_mint: Pubkey::default(),
+                    checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+                    checkpoint_count: 0,
+                    _padding: [0; 13],
                 },
                 200000, //  should be 2000%
+                24657,  // 180 days remaining out of the 4 years max saturation
             ),
             (
                 "Case 6",
@@ -498,7 +1611,13 @@ mod tests {
                     proposal_can_update_after_votes: true,
                     lockup_amount: 10000,
                     proposal_nonce: 0,
-                    _padding: [0; 240],
+                    lockup_early_unlock_penalty_pct: 0,
+                    lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+                    lockup_unstake_cooldown: 0,
+                    emergency_unlock: false,
+                    lockup_voting_curve_len: 0,
+                    lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+                    _padding: [0; 147],
                 },
                 Lockup {
                     ns: Pubkey::new_from_array([0; 32]),
@@ -509,16 +1628,187 @@ mod tests {
                     weighted_start_ts: 0,
                     target_rewards_pct: 100,
                     target_voting_pct: 2000,
-                    _padding: [0; 232],
+                    index: 0,
+                    voted_proposal_end_ts: 0,
+                    perpetual: false,
+                    delegated: false,
+                    unstake_requested_ts: 0,
+                    vesting_amount: 0,
+                    vesting_start_ts: 0,
+                    vesting_cliff_ts: 0,
+                    vesting_end_ts: 0,
+                    receipt_mint: Pubkey::default(),
+                    checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+                    checkpoint_count: 0,
+                    _padding: [0; 13],
                 },
                 200000, //  should be 20x of the amount
+                75000,  // 1.5 years remaining out of the 4 years max saturation
             ),
         ];
 
-        for (name, ns, lockup, expected_voting_power) in test_cases {
-            let voting_power = lockup.voting_power(&ns);
-            assert_eq!(voting_power, expected_voting_power, "{}", name);
+        for (name, mut ns, lockup, expected_duration_mode, expected_decay_mode) in test_cases {
+            for (mode, expected_voting_power) in [
+                (VOTING_POWER_MODE_LOCKUP_DURATION, expected_duration_mode),
+                (VOTING_POWER_MODE_LINEAR_DECAY, expected_decay_mode),
+            ] {
+                ns.lockup_voting_power_mode = mode;
+                let voting_power = lockup.voting_power(&ns);
+                assert_eq!(
+                    voting_power, expected_voting_power,
+                    "{} mode {}",
+                    name, mode
+                );
+                assert_eq!(
+                    lockup.rewards_power(&ns),
+                    expected_voting_power * lockup.target_rewards_pct as u64 / 100,
+                    "{} mode {}",
+                    name,
+                    mode
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lockup_voting_curve() {
+        let one_year = 86400 * 365;
+        let mut ns = Namespace {
+            override_now: 100,
+            lockup_default_target_voting_pct: 500,
+            lockup_min_amount: 1000,
+            lockup_max_saturation: (one_year * 4) as u64,
+            lockup_amount: 10000,
+            ..test_ns()
+        };
+        let mut lockup = Lockup {
+            amount: 10000,
+            end_ts: one_year * 4 + 100,
+            target_rewards_pct: 50,
+            target_voting_pct: 500,
+            ..test_lockup()
+        };
+
+        let two_weeks = 86400 * 14;
+        let half_year = one_year / 2;
+        let points = [(two_weeks, 100), (half_year, 200), (one_year * 4, 500)];
+        for (i, (duration, multiplier_pct)) in points.iter().enumerate() {
+            ns.lockup_voting_curve[i] = VotingCurvePoint {
+                duration: *duration,
+                multiplier_pct: *multiplier_pct,
+            };
+        }
+        ns.lockup_voting_curve_len = points.len() as u8;
+        assert!(ns.valid());
+
+        // (lockup duration, expected voting power)
+        let test_cases = vec![
+            (86400 * 7, 10000),                   // below the first point, 1x
+            (two_weeks, 10000),                   // 1x
+            ((two_weeks + half_year) / 2, 15000), // halfway between 1x and 2x
+            (half_year, 20000),                   // 2x
+            (one_year * 4, 50000),                // 5x
+            (one_year * 5, 50000),                // capped at the last point
+        ];
+        for (duration, expected) in test_cases {
+            lockup.end_ts = lockup.start_ts + duration;
+            assert_eq!(lockup.voting_power(&ns), expected, "{}", duration);
         }
+
+        // boosted lockups scale the boost above 1x against the last point
+        lockup.target_voting_pct = 900;
+        lockup.end_ts = lockup.start_ts + half_year;
+        assert_eq!(lockup.voting_power(&ns), 30000);
+
+        // the linear decay mode doesn't take a curve, its first multiplier would keep the
+        // voting power from decaying below 1x
+        ns.lockup_voting_power_mode = VOTING_POWER_MODE_LINEAR_DECAY;
+        assert!(!ns.valid());
+        ns.lockup_voting_curve_len = 0;
+        assert!(ns.valid());
+        ns.lockup_voting_curve_len = points.len() as u8;
+        ns.lockup_voting_power_mode = VOTING_POWER_MODE_LOCKUP_DURATION;
+
+        // the curve needs to end at lockup_default_target_voting_pct
+        ns.lockup_default_target_voting_pct = 2000;
+        assert!(!ns.valid());
+        ns.lockup_default_target_voting_pct = 500;
+
+        // multipliers cannot decrease
+        ns.lockup_voting_curve[1].multiplier_pct = 50;
+        assert!(!ns.valid());
+    }
+
+    #[test]
+    fn test_perpetual_lockup() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: four_years * 2,
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
+            lockup_min_amount: 1000,
+            lockup_max_saturation: four_years as u64,
+            proposal_min_voting_power_for_quorum: 10000,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 10000,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        };
+        // the stored end_ts has passed, but the lockup is perpetual
+        let mut lockup = Lockup {
+            ns: Pubkey::new_from_array([0; 32]),
+            owner: Pubkey::new_from_array([0; 32]),
+            amount: 10000,
+            start_ts: 0,
+            end_ts: four_years,
+            weighted_start_ts: 0,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: true,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
+        };
+
+        assert_eq!(lockup.effective_end_ts(&ns), four_years * 3);
+        assert_eq!(lockup.voting_power(&ns), 200000);
+        assert!(lockup.valid(&ns));
+
+        ns.lockup_voting_power_mode = VOTING_POWER_MODE_LINEAR_DECAY;
+        assert_eq!(lockup.voting_power(&ns), 200000);
+
+        // deposits ignore the requested end_ts and roll the end_ts forward
+        lockup.deposit(&ns, 10000, ns.now() + 86400 * 30).unwrap();
+        assert_eq!(lockup.amount, 20000);
+        assert_eq!(lockup.end_ts, four_years * 3);
+        assert_eq!(lockup.weighted_start_ts, four_years);
+        assert_eq!(lockup.voting_power(&ns), 400000);
+
+        // without the flag, the stored end_ts applies again
+        lockup.perpetual = false;
+        ns.override_now = four_years * 3;
+        assert_eq!(lockup.voting_power(&ns), 0);
     }
 
     #[test]
@@ -530,7 +1820,7 @@ mod tests {
             deployer: Pubkey::new_from_array([0; 32]),
             security_council: Pubkey::new_from_array([0; 32]),
             review_council: Pubkey::new_from_array([0; 32]),
-            override_now: 0,
+            override_now: 1, // avoid the Clock sysvar, which is unavailable off-chain
             lockup_default_target_rewards_pct: 100,
             lockup_default_target_voting_pct: 2000, // 20x max
             lockup_min_duration: 86400 * 14,         // 14 days
@@ -541,7 +1831,13 @@ mod tests {
             proposal_can_update_after_votes: false,
             lockup_amount: 0,
             proposal_nonce: 0,
-            _padding: [0; 240],
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
         };
 
         // Simulate: 1 token locked for 4 years, then after 3.9 years add 999,999 tokens
@@ -558,7 +1854,19 @@ mod tests {
             weighted_start_ts: four_years - 100_003, // ~3.9 years from T0
             target_rewards_pct: 100,
             target_voting_pct: 2000,
-            _padding: [0; 232],
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
         };
         let vp_attack = lockup_attack.voting_power(&ns);
         // With only ~0.1 year duration, should be close to 1x (amount itself)
@@ -579,7 +1887,19 @@ mod tests {
             weighted_start_ts: 0, // Same as start_ts
             target_rewards_pct: 100,
             target_voting_pct: 2000,
-            _padding: [0; 232],
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
         };
         let vp_normal = lockup_normal.voting_power(&ns);
         assert_eq!(
@@ -599,7 +1919,19 @@ mod tests {
             weighted_start_ts: four_years - (86400 * 365 * 3), // 3-year duration
             target_rewards_pct: 100,
             target_voting_pct: 2000,
-            _padding: [0; 232],
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
         };
         let vp_gradual = lockup_gradual.voting_power(&ns);
         // 3 years is 75% of max saturation, should be between 100% and 2000%
@@ -620,7 +1952,19 @@ mod tests {
             weighted_start_ts: 0, // Should use start_ts
             target_rewards_pct: 100,
             target_voting_pct: 2000,
-            _padding: [0; 232],
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
         };
         let vp_legacy = lockup_legacy.voting_power(&ns);
         // 1 year = 25% of 4 years, should get ~5.75x
@@ -642,111 +1986,1562 @@ mod tests {
             weighted_start_ts: 0,
             target_rewards_pct: 100,
             target_voting_pct: 2000,
-            _padding: [0; 232],
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
         };
         let vp_min = lockup_min.voting_power(&ns);
         assert_eq!(vp_min, 10_000, "Min duration should yield 1x (100%)");
     }
 
     #[test]
-    fn test_has_quorum_false() {
-        let ns = Namespace {
+    fn test_lockup_index_seed() {
+        let ns = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (original, _) =
+            Pubkey::find_program_address(&[b"lockup", ns.as_ref(), owner.as_ref()], &crate::ID);
+        let (index_0, _) = Pubkey::find_program_address(
+            &[
+                b"lockup",
+                ns.as_ref(),
+                owner.as_ref(),
+                Lockup::index_seed(0).as_ref(),
+            ],
+            &crate::ID,
+        );
+        let (index_1, _) = Pubkey::find_program_address(
+            &[
+                b"lockup",
+                ns.as_ref(),
+                owner.as_ref(),
+                Lockup::index_seed(1).as_ref(),
+            ],
+            &crate::ID,
+        );
+        assert_eq!(original, index_0, "index 0 should be the original lockup");
+        assert_ne!(original, index_1, "index 1 should be a different lockup");
+    }
+
+    #[test]
+    fn test_lockup_transfer() {
+        let owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let lockup = Lockup {
+            ns: Pubkey::new_unique(),
+            owner,
+            amount: 5000,
+            start_ts: 100,
+            end_ts: 100 + 86400 * 365,
+            weighted_start_ts: 200,
+            target_rewards_pct: 150,
+            target_voting_pct: 1500,
+            index: 2,
+            voted_proposal_end_ts: 50, // votes that ended before the transfer
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
+        };
+
+        let new_lockup = lockup.transferred(new_owner, 7);
+        assert_eq!(new_lockup.owner, new_owner);
+        assert_eq!(new_lockup.index, 7);
+        assert_eq!(new_lockup.ns, lockup.ns);
+        assert_eq!(new_lockup.amount, 5000);
+        assert_eq!(new_lockup.start_ts, 100);
+        assert_eq!(new_lockup.end_ts, 100 + 86400 * 365);
+        assert_eq!(new_lockup.weighted_start_ts, 200);
+        assert_eq!(new_lockup.target_rewards_pct, 150);
+        assert_eq!(new_lockup.target_voting_pct, 1500);
+        // the vote history is kept with the tokens
+        assert_eq!(new_lockup.voted_proposal_end_ts, 50);
+    }
+
+    #[test]
+    fn test_lockup_deposit() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = Namespace {
             token_mint: Pubkey::new_from_array([0; 32]),
             deployer: Pubkey::new_from_array([0; 32]),
             security_council: Pubkey::new_from_array([0; 32]),
             review_council: Pubkey::new_from_array([0; 32]),
             override_now: 1,
             lockup_default_target_rewards_pct: 100,
-            lockup_default_target_voting_pct: 5000,
-            lockup_min_duration: 86400,
-            lockup_min_amount: 1000,
-            lockup_max_saturation: 86400,
-            proposal_min_voting_power_for_quorum: 100000,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
+            lockup_min_amount: 1,
+            lockup_max_saturation: four_years as u64,
+            proposal_min_voting_power_for_quorum: 10000,
             proposal_min_pass_pct: 60,
-            proposal_can_update_after_votes: true,
-            lockup_amount: 10000,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 0,
             proposal_nonce: 0,
-            _padding: [0; 240],
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
         };
-        let proposal = Proposal {
+        let mut lockup = Lockup {
             ns: Pubkey::new_from_array([0; 32]),
-            nonce: 0,
             owner: Pubkey::new_from_array([0; 32]),
-            uri: "https://123".to_owned(),
+            amount: 0,
             start_ts: 0,
-            end_ts: 100,
-            status: 0,
-            voting_power_choices: [10000, 0, 0, 0, 0, 0],
-            _padding: [0; 240],
+            end_ts: 0,
+            weighted_start_ts: 0,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
         };
-        assert_eq!(proposal.has_quorum(&ns), false);
+
+        // first deposit starts the lockup, and end_ts is capped by lockup_max_saturation
+        lockup.deposit(&ns, 1, four_years * 2).unwrap();
+        assert_eq!(lockup.start_ts, 1);
+        assert_eq!(lockup.weighted_start_ts, 1);
+        assert_eq!(lockup.end_ts, 1 + four_years);
+        assert_eq!(lockup.amount, 1);
+
+        // large top-up right before end_ts only gets a short weighted duration
+        ns.override_now = four_years - 86400;
+        lockup.deposit(&ns, 999_999, 1 + four_years).unwrap();
+        assert_eq!(lockup.amount, 1_000_000);
+        assert_eq!(lockup.end_ts, 1 + four_years);
+        assert!(lockup.end_ts - lockup.weighted_start_ts < 86400 + 200);
+
+        // end_ts cannot be shortened
+        assert!(lockup.deposit(&ns, 1, four_years).is_err());
     }
 
     #[test]
-    fn test_has_quorum_true() {
+    fn test_lockup_deposit_on_behalf() {
+        let one_year = 86400 * 365;
+        let mut ns = test_ns();
+        let mut lockup = Lockup {
+            target_rewards_pct: 0,
+            target_voting_pct: 0,
+            ..test_lockup()
+        };
+
+        // a new lockup takes end_ts and the namespace defaults
+        lockup.deposit_on_behalf(&ns, 1000, 1 + one_year).unwrap();
+        assert_eq!(lockup.end_ts, 1 + one_year);
+        assert_eq!(lockup.target_rewards_pct, 100);
+        assert_eq!(lockup.target_voting_pct, 2000);
+
+        // an existing lockup keeps its end_ts and multipliers, even with a later end_ts
+        lockup.target_rewards_pct = 0;
+        lockup.target_voting_pct = 500;
+        ns.lockup_default_target_voting_pct = 5000;
+        ns.override_now = 1 + one_year / 2;
+        let mut expected = lockup;
+        expected.deposit(&ns, 1000, lockup.end_ts).unwrap();
+        lockup
+            .deposit_on_behalf(&ns, 1000, 1 + one_year * 4)
+            .unwrap();
+        assert_eq!(lockup.end_ts, 1 + one_year);
+        assert_eq!(lockup.target_rewards_pct, 0);
+        assert_eq!(lockup.target_voting_pct, 500);
+        assert_eq!(lockup.amount, 2000);
+        assert_eq!(lockup.weighted_start_ts, expected.weighted_start_ts);
+    }
+
+    #[test]
+    fn test_lockup_deposit_on_behalf_without_end_ts() {
+        let one_year = 86400 * 365;
+        let ns = Namespace {
+            override_now: 1 + one_year / 2,
+            ..test_ns()
+        };
+        // staked by the owner with end_ts 0
+        let mut lockup = Lockup {
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 0,
+            weighted_start_ts: 1,
+            target_voting_pct: 500,
+            ..test_lockup()
+        };
+
+        // the top-up needs an end_ts, at least lockup_min_duration from now
+        assert!(lockup.deposit_on_behalf(&ns, 1000, 0).is_err());
+        assert!(lockup
+            .deposit_on_behalf(&ns, 1000, lockup.min_end_ts(&ns) - 1)
+            .is_err());
+
+        lockup
+            .deposit_on_behalf(&ns, 1000, 1 + one_year * 2)
+            .unwrap();
+        assert_eq!(lockup.amount, 2000);
+        assert_eq!(lockup.end_ts, 1 + one_year * 2);
+        assert_eq!(lockup.target_rewards_pct, 100);
+        assert_eq!(lockup.target_voting_pct, 500);
+        assert!(lockup.valid(&ns));
+    }
+
+    #[test]
+    fn test_lockup_valid_target_pcts() {
+        let mut lockup = test_lockup();
+
+        // target_voting_pct is bounded to 1x..25x
+        for (target_voting_pct, valid) in [(99, false), (100, true), (2500, true), (2501, false)] {
+            lockup.target_voting_pct = target_voting_pct;
+            assert_eq!(lockup.valid_target_pcts(), valid, "{}", target_voting_pct);
+        }
+
+        // target_rewards_pct can be removed or raised by the security council
+        lockup.target_voting_pct = 100;
+        for target_rewards_pct in [0, 100, u16::MAX] {
+            lockup.target_rewards_pct = target_rewards_pct;
+            assert!(lockup.valid_target_pcts());
+        }
+    }
+
+    #[test]
+    fn test_stake_for() {
+        let one_year = 86400 * 365;
+        let mut ns = Namespace {
+            lockup_min_amount: 1000,
+            ..test_ns()
+        };
+        let ns_key = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let beneficiary = Pubkey::new_unique();
+
+        // a new lockup belongs to the beneficiary, not the payer, with the namespace defaults
+        let mut lockup = Lockup {
+            target_rewards_pct: 0,
+            target_voting_pct: 0,
+            ..test_lockup()
+        };
+        assert!(lockup
+            .stake_for(&ns, ns_key, beneficiary, 1000, ns.now() + 86400)
+            .is_err());
+        lockup
+            .stake_for(&ns, ns_key, beneficiary, 1000, 1 + one_year * 2)
+            .unwrap();
+        assert_eq!(lockup.ns, ns_key);
+        assert_eq!(lockup.owner, beneficiary);
+        assert_ne!(lockup.owner, payer);
+        assert_eq!(lockup.end_ts, 1 + one_year * 2);
+        assert_eq!(lockup.target_rewards_pct, 100);
+        assert_eq!(lockup.target_voting_pct, 2000);
+
+        // every top-up needs lockup_min_amount and keeps the end_ts and multipliers
+        lockup.target_voting_pct = 500;
+        ns.override_now = 1 + one_year;
+        assert!(lockup.stake_for(&ns, ns_key, beneficiary, 999, 0).is_err());
+        lockup
+            .stake_for(&ns, ns_key, beneficiary, 1000, 1 + one_year * 4)
+            .unwrap();
+        assert_eq!(lockup.amount, 2000);
+        assert_eq!(lockup.end_ts, 1 + one_year * 2);
+        assert_eq!(lockup.target_voting_pct, 500);
+
+        // no deposits in the emergency unlock mode
+        ns.emergency_unlock = true;
+        assert!(lockup.stake_for(&ns, ns_key, beneficiary, 1000, 0).is_err());
+        ns.emergency_unlock = false;
+
+        // nor into expired lockups or lockups staked with end_ts 0
+        ns.override_now = 1 + one_year * 2;
+        assert!(lockup.stake_for(&ns, ns_key, beneficiary, 1000, 0).is_err());
+        ns.override_now = 1 + one_year;
+        lockup.end_ts = 0;
+        assert!(lockup
+            .stake_for(&ns, ns_key, beneficiary, 1000, 1 + one_year * 2)
+            .is_err());
+        assert_eq!(lockup.amount, 2000);
+    }
+
+    #[test]
+    fn test_partial_unstake_amount() {
         let ns = Namespace {
             token_mint: Pubkey::new_from_array([0; 32]),
             deployer: Pubkey::new_from_array([0; 32]),
             security_council: Pubkey::new_from_array([0; 32]),
             review_council: Pubkey::new_from_array([0; 32]),
-            override_now: 90, // now() < proposal.end_ts
+            override_now: 1,
             lockup_default_target_rewards_pct: 100,
-            lockup_default_target_voting_pct: 5000,
-            lockup_min_duration: 86400,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
             lockup_min_amount: 1000,
-            lockup_max_saturation: 86400,
-            proposal_min_voting_power_for_quorum: 100,
+            lockup_max_saturation: 86400 * 365 * 4,
+            proposal_min_voting_power_for_quorum: 10000,
             proposal_min_pass_pct: 60,
-            proposal_can_update_after_votes: true,
-            lockup_amount: 10000,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 0,
             proposal_nonce: 0,
-            _padding: [0; 240],
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
         };
-        let proposal = Proposal {
+        let lockup = Lockup {
             ns: Pubkey::new_from_array([0; 32]),
-            nonce: 0,
             owner: Pubkey::new_from_array([0; 32]),
-            uri: "https://123".to_owned(),
+            amount: 5000,
             start_ts: 0,
-            end_ts: 100,
-            status: 0,
-            voting_power_choices: [100, 100, 0, 0, 0, 0],
-            _padding: [0; 240],
+            end_ts: 0,
+            weighted_start_ts: 0,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            _padding: [0; 13],
         };
-        assert_eq!(proposal.has_quorum(&ns), true);
+
+        // (requested amount, expected withdrawn amount)
+        let test_cases = vec![
+            (1000, 1000),
+            (4000, 4000), // the remainder is exactly lockup_min_amount
+            (4001, 5000), // the remainder would fall below lockup_min_amount
+            (5000, 5000),
+        ];
+        for (amount, expected) in test_cases {
+            assert_eq!(
+                lockup.partial_unstake_amount(&ns, amount).unwrap(),
+                expected
+            );
+        }
     }
 
     #[test]
-    fn test_has_passed() {
-        let ns = Namespace {
+    fn test_lockup_relock() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = Namespace {
             token_mint: Pubkey::new_from_array([0; 32]),
             deployer: Pubkey::new_from_array([0; 32]),
             security_council: Pubkey::new_from_array([0; 32]),
             review_council: Pubkey::new_from_array([0; 32]),
-            override_now: 101,
+            override_now: 1,
             lockup_default_target_rewards_pct: 100,
-            lockup_default_target_voting_pct: 5000,
-            lockup_min_duration: 86400,
-            lockup_min_amount: 1000,
-            lockup_max_saturation: 86400,
-            proposal_min_voting_power_for_quorum: 100,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
+            lockup_min_amount: 1,
+            lockup_max_saturation: four_years as u64,
+            proposal_min_voting_power_for_quorum: 10000,
             proposal_min_pass_pct: 60,
-            proposal_can_update_after_votes: true,
-            lockup_amount: 10000,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 0,
             proposal_nonce: 0,
-            _padding: [0; 240],
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
         };
-        let proposal = Proposal {
+        let mut lockup = Lockup {
             ns: Pubkey::new_from_array([0; 32]),
-            nonce: 0,
             owner: Pubkey::new_from_array([0; 32]),
-            uri: "https://123".to_owned(),
-            start_ts: 0,
-            end_ts: 100,
-            status: 0,
-            voting_power_choices: [10000, 0, 0, 0, 0, 0],
-            _padding: [0; 240],
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            weighted_start_ts: 1,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
         };
-        assert_eq!(proposal.has_passed(&ns), true);
+
+        // active lockups cannot be relocked
+        assert!(lockup.relock(&ns, 0, four_years * 2).is_err());
+
+        // expired lockups restart from now, end_ts is capped by lockup_max_saturation
+        ns.override_now = four_years * 2;
+        lockup.relock(&ns, 500, four_years * 10).unwrap();
+        assert_eq!(lockup.amount, 1500);
+        assert_eq!(lockup.start_ts, four_years * 2);
+        assert_eq!(lockup.weighted_start_ts, four_years * 2);
+        assert_eq!(lockup.end_ts, four_years * 3);
+        assert_eq!(lockup.voting_power(&ns), 1500 * 20);
+    }
+
+    #[test]
+    fn test_lockup_checkpoints() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = Namespace {
+            lockup_max_saturation: four_years as u64,
+            ..test_ns()
+        };
+        let mut lockup = test_lockup();
+
+        // without checkpoints, e.g. lockups created before them, the current state is used
+        assert_eq!(lockup.voting_power_at(&ns, 1), 0);
+
+        lockup.deposit(&ns, 1000, 1 + four_years).unwrap();
+        lockup.write_checkpoint(&ns);
+        assert_eq!(lockup.voting_power_at(&ns, 1), 1000 * 20);
+
+        // a top-up after the proposal start doesn't count
+        ns.override_now = 100;
+        lockup.deposit(&ns, 1000, 1 + four_years).unwrap();
+        lockup.write_checkpoint(&ns);
+        assert_eq!(lockup.voting_power_at(&ns, 50), 1000 * 20);
+        assert!(lockup.voting_power_at(&ns, 100) > 1000 * 20);
+
+        // changes in the same block overwrite the last checkpoint
+        lockup.deposit(&ns, 1000, 1 + four_years).unwrap();
+        lockup.write_checkpoint(&ns);
+        assert_eq!(lockup.checkpoint_count, 2);
+        assert_eq!(lockup.checkpoints[1].amount, 3000);
+
+        // the lockup didn't exist before the first checkpoint
+        assert_eq!(lockup.voting_power_at(&ns, 0), 0);
+
+        // once all the slots are used, the adjacent checkpoints that differ the least are
+        // merged into their lower bound, the history can only under-count
+        let mut expected = vec![];
+        for now in [200, 300, 400] {
+            ns.override_now = now;
+            lockup.deposit(&ns, 1000, 1 + four_years).unwrap();
+            lockup.write_checkpoint(&ns);
+            expected.push(lockup.voting_power(&ns));
+        }
+        assert_eq!(lockup.checkpoint_count, MAX_LOCKUP_CHECKPOINTS as u32);
+        assert!(lockup.checkpoints.windows(2).all(|c| c[0].ts < c[1].ts));
+        assert_eq!(lockup.voting_power_at(&ns, 50), 1000 * 20);
+        assert!(lockup.voting_power_at(&ns, 100) > 3000 * 19);
+        assert!(lockup.voting_power_at(&ns, 200) <= expected[0]);
+        assert!(lockup.voting_power_at(&ns, 300) <= expected[1]);
+        assert_eq!(lockup.voting_power_at(&ns, 400), expected[2]);
+    }
+
+    #[test]
+    fn test_lockup_checkpoints_griefing() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = test_ns();
+        let mut lockup = Lockup {
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            ..test_lockup()
+        };
+        lockup.write_checkpoint(&ns);
+
+        // the owner doubles the lockup between the starts of two proposals
+        ns.override_now = 150;
+        lockup.deposit(&ns, 1000, 1 + four_years).unwrap();
+        lockup.write_checkpoint(&ns);
+        let starts = [100, 200];
+        let expected: Vec<u64> = starts
+            .iter()
+            .map(|start_ts| lockup.voting_power_at(&ns, *start_ts))
+            .collect();
+        assert_eq!(expected[0], 1000 * 20);
+        assert!(expected[1] > 2000 * 19);
+
+        // anyone can top up the lockup with dust after the proposal starts, the dust
+        // checkpoints are merged with each other instead of pushing out the proposal starts
+        for now in 301..320 {
+            ns.override_now = now;
+            lockup.deposit_on_behalf(&ns, 1, 0).unwrap();
+            lockup.write_checkpoint(&ns);
+        }
+        for (start_ts, expected) in starts.iter().zip(expected) {
+            assert_eq!(lockup.voting_power_at(&ns, *start_ts), expected);
+        }
+        assert_eq!(
+            lockup.voting_power_at(&ns, ns.override_now),
+            lockup.voting_power(&ns)
+        );
+    }
+
+    #[test]
+    fn test_lockup_checkpoints_legacy() {
+        let four_years = 86400 * 365 * 4;
+        let ns = Namespace {
+            override_now: 200,
+            ..test_ns()
+        };
+        let mut lockup = Lockup {
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            ..test_lockup()
+        };
+
+        // lockups created before the checkpoints vote with their current state
+        assert_eq!(lockup.voting_power_at(&ns, 100), 1000 * 20);
+
+        // a stake_for after the proposal start records that state before the first change
+        lockup.seed_checkpoint();
+        lockup.deposit_on_behalf(&ns, 1000, 0).unwrap();
+        lockup.write_checkpoint(&ns);
+        assert_eq!(lockup.checkpoint_count, 2);
+        assert_eq!(lockup.voting_power_at(&ns, 100), 1000 * 20);
+        assert!(lockup.voting_power_at(&ns, 200) > 1000 * 20);
+
+        // only once, and new lockups have nothing to record
+        lockup.seed_checkpoint();
+        assert_eq!(lockup.checkpoint_count, 2);
+        let mut new_lockup = lockup;
+        new_lockup.amount = 0;
+        new_lockup.checkpoint_count = 0;
+        new_lockup.seed_checkpoint();
+        assert_eq!(new_lockup.checkpoint_count, 0);
+    }
+
+    #[test]
+    fn test_lockup_checkpoints_clawback() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = test_ns();
+        let mut lockup = Lockup {
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            vesting_amount: 1000,
+            vesting_start_ts: 1,
+            vesting_cliff_ts: 1,
+            vesting_end_ts: 1 + four_years / 2,
+            ..test_lockup()
+        };
+        lockup.write_checkpoint(&ns);
+
+        // only the vested tokens count as of the proposal start
+        let start_ts = 1 + four_years / 8;
+        assert_eq!(lockup.voting_power_at(&ns, start_ts), 250 * 20);
+
+        // the clawback stops the vesting, but the proposal start still vests along the schedule
+        ns.override_now = 1 + four_years / 4;
+        lockup.amount -= lockup.clawback_amount(&ns);
+        lockup.vesting_amount = 0;
+        lockup.write_checkpoint(&ns);
+        assert_eq!(lockup.voting_power_at(&ns, start_ts), 250 * 20);
+        assert_eq!(lockup.voting_power_at(&ns, ns.override_now), 500 * 20);
+    }
+
+    #[test]
+    fn test_lockup_checkpoints_multipliers() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = test_ns();
+        let mut lockup = Lockup {
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            ..test_lockup()
+        };
+        lockup.write_checkpoint(&ns);
+
+        // the security council overrides the multiplier after the proposal start
+        ns.override_now = 200;
+        lockup.target_voting_pct = 2500;
+        lockup.write_checkpoint(&ns);
+        assert_eq!(lockup.voting_power_at(&ns, 100), 1000 * 20);
+        assert_eq!(lockup.voting_power_at(&ns, 200), 1000 * 25);
+
+        ns.override_now = 300;
+        lockup.target_voting_pct = 100;
+        lockup.write_checkpoint(&ns);
+        assert_eq!(lockup.voting_power_at(&ns, 100), 1000 * 20);
+        assert_eq!(lockup.voting_power_at(&ns, 300), 1000);
+    }
+
+    #[test]
+    fn test_unstake_cooldown() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = test_ns();
+        let mut lockup = Lockup {
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            weighted_start_ts: 1,
+            ..test_lockup()
+        };
+
+        // without a cooldown, expired lockups can be unstaked right away
+        assert!(lockup.unstake_cooldown_ended(&ns));
+
+        ns.lockup_unstake_cooldown = 86400 * 7;
+        ns.override_now = 1 + four_years;
+        assert!(!lockup.unstake_cooldown_ended(&ns));
+
+        // the cooldown starts from request_unstake, and the lockup has no voting power during it
+        ns.override_now = 1 + four_years + 100;
+        lockup.unstake_requested_ts = ns.override_now;
+        assert_eq!(lockup.voting_power(&ns), 0);
+        ns.override_now += 86400 * 7 - 1;
+        assert!(!lockup.unstake_cooldown_ended(&ns));
+        ns.override_now += 1;
+        assert!(lockup.unstake_cooldown_ended(&ns));
+
+        // relock resets the request
+        lockup.relock(&ns, 0, ns.override_now + four_years).unwrap();
+        assert_eq!(lockup.unstake_requested_ts, 0);
+        assert_eq!(lockup.voting_power(&ns), 1000 * 20);
+    }
+
+    #[test]
+    fn test_unstake_delegated_in_emergency() {
+        let one_year = 86400 * 365;
+        let mut ns = test_ns();
+        let mut lockup = Lockup {
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + one_year,
+            voted_proposal_end_ts: 86400 * 7,
+            delegated: true,
+            ..test_lockup()
+        };
+
+        // the delegate voted with the lockup, the owner can neither revoke nor unstake
+        assert!(lockup.has_active_votes(&ns));
+        assert!(!lockup.can_unstake_delegated(&ns, true));
+
+        // in the emergency unlock mode the owner can unstake right away, but only by closing
+        // the delegation together with the lockup
+        ns.emergency_unlock = true;
+        assert!(lockup.has_active_votes(&ns));
+        assert!(!lockup.can_unstake_delegated(&ns, false));
+        assert!(lockup.can_unstake_delegated(&ns, true));
+
+        lockup.delegated = false;
+        assert!(lockup.can_unstake_delegated(&ns, false));
+    }
+
+    #[test]
+    fn test_vesting_lockup() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = Namespace {
+            lockup_max_saturation: four_years as u64,
+            ..test_ns()
+        };
+        let lockup = Lockup {
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            weighted_start_ts: 1,
+            vesting_amount: 1000,
+            vesting_start_ts: 1,
+            vesting_cliff_ts: 1 + four_years / 4,
+            vesting_end_ts: 1 + four_years / 2,
+            ..test_lockup()
+        };
+
+        // nothing is vested before the cliff, so there is no voting power yet
+        assert_eq!(lockup.unvested_amount(&ns), 1000);
+        assert_eq!(lockup.voting_power(&ns), 0);
+
+        // after the cliff, the release is linear from vesting_start_ts
+        ns.override_now = 1 + four_years / 4;
+        assert_eq!(lockup.unvested_amount(&ns), 500);
+        assert_eq!(lockup.vested_amount(&ns), 500);
+        assert_eq!(lockup.voting_power(&ns), 500 * 20);
+
+        // fully vested after vesting_end_ts
+        ns.override_now = 1 + four_years / 2;
+        assert_eq!(lockup.unvested_amount(&ns), 0);
+        assert_eq!(lockup.vested_amount(&ns), 1000);
+    }
+
+    #[test]
+    fn test_vesting_lockup_emergency_unlock() {
+        let four_years = 86400 * 365 * 4;
+        let mut ns = Namespace {
+            lockup_min_amount: 100,
+            ..test_ns()
+        };
+        let mut lockup = Lockup {
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            vesting_amount: 1000,
+            vesting_start_ts: 1,
+            vesting_cliff_ts: 1,
+            vesting_end_ts: 1 + four_years / 2,
+            ..test_lockup()
+        };
+
+        // halfway through the vesting, only the vested half can be withdrawn
+        ns.override_now = 1 + four_years / 4;
+        assert_eq!(lockup.vested_amount(&ns), 500);
+        assert_eq!(lockup.unvested_amount(&ns), 500);
+
+        // a remainder below the minimum can't fall back to a full unstake while it's vesting
+        lockup.amount = 550;
+        assert_eq!(lockup.partial_unstake_amount(&ns, 50).unwrap(), 50);
+        ns.lockup_min_amount = 600;
+        assert!(lockup.partial_unstake_amount(&ns, 50).is_err());
+
+        // the emergency unlock doesn't release the unvested tokens, the security council
+        // claws them back before the rest can be unstaked
+        ns.emergency_unlock = true;
+        assert_eq!(lockup.vested_amount(&ns), 50);
+        assert!(lockup.partial_unstake_amount(&ns, 50).is_err());
+        let mut clawed_back = lockup;
+        clawed_back.amount -= clawed_back.clawback_amount(&ns);
+        clawed_back.vesting_amount = 0;
+        assert_eq!(clawed_back.amount, 50);
+        assert_eq!(clawed_back.vested_amount(&ns), 50);
+        assert_eq!(clawed_back.partial_unstake_amount(&ns, 50).unwrap(), 50);
+        ns.emergency_unlock = false;
+        ns.lockup_min_amount = 100;
+
+        // once fully vested, it falls back to a full unstake
+        ns.override_now = 1 + four_years / 2;
+        assert_eq!(lockup.partial_unstake_amount(&ns, 500).unwrap(), 550);
+
+        // clawback takes the unvested tokens, capped by what's left in the lockup
+        ns.override_now = 1 + four_years / 4;
+        assert_eq!(lockup.clawback_amount(&ns), 500);
+        lockup.amount = 300;
+        assert_eq!(lockup.clawback_amount(&ns), 300);
+    }
+
+    #[test]
+    fn test_lockup_merge() {
+        let one_year = 86400 * 365;
+        let ns = Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: one_year,
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
+            lockup_min_amount: 1,
+            lockup_max_saturation: (one_year * 4) as u64,
+            proposal_min_voting_power_for_quorum: 10000,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 0,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        };
+        // locked for 4 years at T0
+        let mut lockup = Lockup {
+            ns: Pubkey::new_from_array([0; 32]),
+            owner: Pubkey::new_from_array([0; 32]),
+            amount: 1000,
+            start_ts: 0,
+            end_ts: one_year * 4,
+            weighted_start_ts: 0,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
+        };
+        // locked for 1 year at T0 + 1 year
+        let other = Lockup {
+            amount: 1000,
+            start_ts: one_year,
+            end_ts: one_year * 2,
+            weighted_start_ts: one_year,
+            index: 1,
+            voted_proposal_end_ts: one_year + 100,
+            ..lockup
+        };
+
+        // 1000 * 4y + 1000 * 3y => 2000 * 3.5y
+        lockup.merge(&ns, &other).unwrap();
+        assert_eq!(lockup.amount, 2000);
+        assert_eq!(lockup.start_ts, one_year);
+        assert_eq!(lockup.end_ts, one_year * 4);
+        assert_eq!(lockup.weighted_start_ts, one_year / 2);
+        assert_eq!(lockup.voted_proposal_end_ts, one_year + 100);
+
+        // expired lockups cannot be merged
+        let expired = Lockup {
+            end_ts: one_year,
+            ..other
+        };
+        assert!(lockup.merge(&ns, &expired).is_err());
+    }
+
+    #[test]
+    fn test_lockup_merge_matches_deposit() {
+        let four_years = 86400 * 365 * 4;
+        let ns = Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: 86400 * 365,
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
+            lockup_min_amount: 1,
+            lockup_max_saturation: 86400 * 365 * 4,
+            proposal_min_voting_power_for_quorum: 10000,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 0,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        };
+        let mut lockup = Lockup {
+            ns: Pubkey::new_from_array([0; 32]),
+            owner: Pubkey::new_from_array([0; 32]),
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            weighted_start_ts: 1,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
+        };
+
+        // merging a lockup staked now conserves the same area as depositing into this one
+        let mut other = lockup;
+        other.amount = 500;
+        other.start_ts = ns.now();
+        other.weighted_start_ts = ns.now();
+        let mut deposited = lockup;
+        deposited.deposit(&ns, 500, lockup.end_ts).unwrap();
+        lockup.merge(&ns, &other).unwrap();
+
+        assert_eq!(lockup.amount, deposited.amount);
+        assert_eq!(lockup.end_ts, deposited.end_ts);
+        assert_eq!(lockup.weighted_start_ts, deposited.weighted_start_ts);
+        assert_eq!(lockup.voting_power(&ns), deposited.voting_power(&ns));
+    }
+
+    #[test]
+    fn test_lockup_split() {
+        let four_years = 86400 * 365 * 4;
+        let ns = Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: 86400 * 365,
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
+            lockup_min_amount: 1000,
+            lockup_max_saturation: 86400 * 365 * 4,
+            proposal_min_voting_power_for_quorum: 10000,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 0,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        };
+        let lockup = Lockup {
+            ns: Pubkey::new_from_array([0; 32]),
+            owner: Pubkey::new_from_array([0; 32]),
+            amount: 5000,
+            start_ts: 1,
+            end_ts: 1 + four_years,
+            weighted_start_ts: 100,
+            target_rewards_pct: 150,
+            target_voting_pct: 1500,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
+        };
+
+        // (split amount, expected validity of both halves)
+        let test_cases = vec![
+            (1000, true),
+            (2500, true),
+            (4000, true),
+            (999, false),  // the new lockup is below lockup_min_amount
+            (4001, false), // the remaining lockup is below lockup_min_amount
+        ];
+        for (amount, expected_valid) in test_cases {
+            let mut remaining = lockup;
+            let new_lockup = remaining.split(amount, 1);
+            assert_eq!(new_lockup.amount, amount);
+            assert_eq!(remaining.amount, lockup.amount - amount);
+            assert_eq!(new_lockup.index, 1);
+            assert_eq!(remaining.index, 0);
+            for half in [&remaining, &new_lockup] {
+                assert_eq!(half.end_ts, lockup.end_ts);
+                assert_eq!(half.effective_start_ts(), lockup.effective_start_ts());
+                assert_eq!(half.target_voting_pct, lockup.target_voting_pct);
+                assert_eq!(half.target_rewards_pct, lockup.target_rewards_pct);
+            }
+            assert_eq!(
+                remaining.valid(&ns) && new_lockup.valid(&ns),
+                expected_valid,
+                "split amount {}",
+                amount
+            );
+        }
+    }
+
+    #[test]
+    fn test_early_unlock_penalty() {
+        let ns = Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: 1000,
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
+            lockup_min_amount: 1,
+            lockup_max_saturation: 86400 * 365 * 4,
+            proposal_min_voting_power_for_quorum: 10000,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 0,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 50,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        };
+        let mut lockup = Lockup {
+            ns: Pubkey::new_from_array([0; 32]),
+            owner: Pubkey::new_from_array([0; 32]),
+            amount: 1_000_000,
+            start_ts: 0,
+            end_ts: 1000 + 86400 * 365 * 4,
+            weighted_start_ts: 0,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: false,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
+        };
+
+        // remaining time at max saturation, full penalty pct
+        assert_eq!(lockup.early_unlock_penalty(&ns), 500_000);
+
+        // remaining time at half of max saturation, half of the penalty pct
+        lockup.end_ts = 1000 + 86400 * 365 * 2;
+        assert_eq!(lockup.early_unlock_penalty(&ns), 250_000);
+
+        // remaining time above max saturation is capped
+        lockup.end_ts = 1000 + 86400 * 365 * 8;
+        assert_eq!(lockup.early_unlock_penalty(&ns), 500_000);
+
+        // expired lockup has no penalty
+        lockup.end_ts = 1000;
+        assert_eq!(lockup.early_unlock_penalty(&ns), 0);
+    }
+
+    #[test]
+    fn test_has_quorum_false() {
+        let ns = Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: 1,
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 5000,
+            lockup_min_duration: 86400,
+            lockup_min_amount: 1000,
+            lockup_max_saturation: 86400,
+            proposal_min_voting_power_for_quorum: 100000,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: true,
+            lockup_amount: 10000,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        };
+        let proposal = Proposal {
+            ns: Pubkey::new_from_array([0; 32]),
+            nonce: 0,
+            owner: Pubkey::new_from_array([0; 32]),
+            uri: "https://123".to_owned(),
+            start_ts: 0,
+            end_ts: 100,
+            status: 0,
+            voting_power_choices: [10000, 0, 0, 0, 0, 0],
+            winning_choice: 0,
+            num_choices: 0,
+            choice_labels: [[0; MAX_CHOICE_LABEL_LEN]; MAX_VOTING_CHOICES],
+            min_voting_power_for_quorum: 0,
+            min_pass_pct: 0,
+            _padding: [0; 132],
+        };
+        assert_eq!(proposal.has_quorum(&ns), false);
+    }
+
+    #[test]
+    fn test_has_quorum_true() {
+        let ns = Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: 90, // now() < proposal.end_ts
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 5000,
+            lockup_min_duration: 86400,
+            lockup_min_amount: 1000,
+            lockup_max_saturation: 86400,
+            proposal_min_voting_power_for_quorum: 100,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: true,
+            lockup_amount: 10000,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        };
+        let proposal = Proposal {
+            ns: Pubkey::new_from_array([0; 32]),
+            nonce: 0,
+            owner: Pubkey::new_from_array([0; 32]),
+            uri: "https://123".to_owned(),
+            start_ts: 0,
+            end_ts: 100,
+            status: 0,
+            voting_power_choices: [100, 100, 0, 0, 0, 0],
+            winning_choice: 0,
+            num_choices: 0,
+            choice_labels: [[0; MAX_CHOICE_LABEL_LEN]; MAX_VOTING_CHOICES],
+            min_voting_power_for_quorum: 0,
+            min_pass_pct: 0,
+            _padding: [0; 132],
+        };
+        assert_eq!(proposal.has_quorum(&ns), true);
+    }
+
+    #[test]
+    fn test_has_passed() {
+        let ns = Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: 101,
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 5000,
+            lockup_min_duration: 86400,
+            lockup_min_amount: 1000,
+            lockup_max_saturation: 86400,
+            proposal_min_voting_power_for_quorum: 100,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: true,
+            lockup_amount: 10000,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        };
+        let proposal = Proposal {
+            ns: Pubkey::new_from_array([0; 32]),
+            nonce: 0,
+            owner: Pubkey::new_from_array([0; 32]),
+            uri: "https://123".to_owned(),
+            start_ts: 0,
+            end_ts: 100,
+            status: 0,
+            voting_power_choices: [10000, 0, 0, 0, 0, 0],
+            winning_choice: 0,
+            num_choices: 0,
+            choice_labels: [[0; MAX_CHOICE_LABEL_LEN]; MAX_VOTING_CHOICES],
+            min_voting_power_for_quorum: 0,
+            min_pass_pct: 0,
+            _padding: [0; 132],
+        };
+        assert_eq!(proposal.has_passed(&ns), true);
+    }
+
+    #[test]
+    fn test_delegated_voting_power() {
+        assert!(!Delegation::valid_pairs_len(0));
+        assert!(!Delegation::valid_pairs_len(3));
+        assert!(Delegation::valid_pairs_len(2));
+        assert!(Delegation::valid_pairs_len(MAX_VOTE_LOCKUPS * 2));
+        assert!(!Delegation::valid_pairs_len(MAX_VOTE_LOCKUPS * 2 + 2));
+
+        let ns = Namespace {
+            token_mint: Pubkey::new_from_array([0; 32]),
+            deployer: Pubkey::new_from_array([0; 32]),
+            security_council: Pubkey::new_from_array([0; 32]),
+            review_council: Pubkey::new_from_array([0; 32]),
+            override_now: 1,
+            lockup_default_target_rewards_pct: 100,
+            lockup_default_target_voting_pct: 2000,
+            lockup_min_duration: 86400 * 14,
+            lockup_min_amount: 1,
+            lockup_max_saturation: 86400 * 365 * 4,
+            proposal_min_voting_power_for_quorum: 10000,
+            proposal_min_pass_pct: 60,
+            proposal_can_update_after_votes: false,
+            lockup_amount: 0,
+            proposal_nonce: 0,
+            lockup_early_unlock_penalty_pct: 0,
+            lockup_voting_power_mode: VOTING_POWER_MODE_LOCKUP_DURATION,
+            lockup_unstake_cooldown: 0,
+            emergency_unlock: false,
+            lockup_voting_curve_len: 0,
+            lockup_voting_curve: [VotingCurvePoint::default(); MAX_VOTING_CURVE_POINTS],
+            _padding: [0; 147],
+        };
+        let lockup_a = Lockup {
+            ns: Pubkey::new_from_array([0; 32]),
+            owner: Pubkey::new_unique(),
+            amount: 1000,
+            start_ts: 1,
+            end_ts: 1 + 86400 * 365,
+            weighted_start_ts: 0,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: true,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
+        };
+        let lockup_b = Lockup {
+            ns: Pubkey::new_from_array([0; 32]),
+            owner: Pubkey::new_unique(),
+            amount: 3000,
+            start_ts: 1,
+            end_ts: 1 + 86400 * 365 * 2,
+            weighted_start_ts: 0,
+            target_rewards_pct: 100,
+            target_voting_pct: 2000,
+            index: 0,
+            voted_proposal_end_ts: 0,
+            perpetual: false,
+            delegated: true,
+            unstake_requested_ts: 0,
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+            receipt_mint: Pubkey::default(),
+            checkpoints: [LockupCheckpoint::default(); MAX_LOCKUP_CHECKPOINTS],
+            checkpoint_count: 0,
+            _padding: [0; 13],
+        };
+        let proposal = Proposal {
+            ns: Pubkey::new_from_array([0; 32]),
+            nonce: 0,
+            owner: Pubkey::new_from_array([0; 32]),
+            uri: "https://123".to_owned(),
+            winning_choice: 0,
+            start_ts: 1,
+            end_ts: 86400 * 7,
+            status: 0,
+            voting_power_choices: [0; MAX_VOTING_CHOICES],
+            num_choices: 0,
+            choice_labels: [[0; MAX_CHOICE_LABEL_LEN]; MAX_VOTING_CHOICES],
+            min_voting_power_for_quorum: 0,
+            min_pass_pct: 0,
+            _padding: [0; 132],
+        };
+        let ns_key = Pubkey::new_from_array([0; 32]);
+        let delegate = Pubkey::new_unique();
+        let (key_a, key_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let delegation = |lockup_key: Pubkey, lockup: &Lockup| Delegation {
+            ns: ns_key,
+            lockup: lockup_key,
+            owner: lockup.owner,
+            delegate,
+            _padding: [0; 240],
+        };
+        let pair_a = (delegation(key_a, &lockup_a), key_a, lockup_a);
+        let pair_b = (delegation(key_b, &lockup_b), key_b, lockup_b);
+
+        // the voting power of the delegated lockups adds up
+        let (voting_power, lockup_keys) = Delegation::delegated_voting_power(
+            &ns,
+            &ns_key,
+            &proposal,
+            &delegate,
+            &[pair_a, pair_b],
+        )
+        .unwrap();
+        assert_eq!(
+            voting_power,
+            lockup_a.voting_power(&ns) + lockup_b.voting_power(&ns)
+        );
+        assert!(voting_power > 0);
+        assert_eq!(lockup_keys, vec![key_a, key_b]);
+
+        // the same lockup cannot be counted twice
+        assert!(Delegation::delegated_voting_power(
+            &ns,
+            &ns_key,
+            &proposal,
+            &delegate,
+            &[pair_a, pair_a]
+        )
+        .is_err());
+
+        // the delegation must point at the lockup it is paired with
+        let mismatched = (delegation(key_b, &lockup_b), key_a, lockup_a);
+        assert!(Delegation::delegated_voting_power(
+            &ns,
+            &ns_key,
+            &proposal,
+            &delegate,
+            &[mismatched]
+        )
+        .is_err());
+
+        // only the delegate can use the delegation
+        assert!(Delegation::delegated_voting_power(
+            &ns,
+            &ns_key,
+            &proposal,
+            &Pubkey::new_unique(),
+            &[pair_a]
+        )
+        .is_err());
+
+        // the lockup must still be delegated
+        let mut undelegated = pair_a;
+        undelegated.2.delegated = false;
+        assert!(Delegation::delegated_voting_power(
+            &ns,
+            &ns_key,
+            &proposal,
+            &delegate,
+            &[undelegated]
+        )
+        .is_err());
+
+        // the lockup must outlast the proposal
+        let mut expiring = pair_a;
+        expiring.2.end_ts = proposal.end_ts;
+        assert!(Delegation::delegated_voting_power(
+            &ns,
+            &ns_key,
+            &proposal,
+            &delegate,
+            &[expiring]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_proposal_governance_snapshot() {
+        let mut ns = Namespace {
+            override_now: 101,
+            lockup_default_target_voting_pct: 5000,
+            lockup_min_duration: 86400,
+            lockup_min_amount: 1000,
+            lockup_max_saturation: 86400,
+            proposal_min_voting_power_for_quorum: 100,
+            proposal_can_update_after_votes: true,
+            lockup_amount: 10000,
+            ..test_ns()
+        };
+        let mut proposal = Proposal {
+            end_ts: 100,
+            voting_power_choices: [700, 300, 0, 0, 0, 0],
+            min_voting_power_for_quorum: 100,
+            min_pass_pct: 60,
+            ..test_proposal()
+        };
+        assert!(proposal.has_quorum(&ns));
+        assert!(proposal.has_passed(&ns));
+
+        // updating the namespace in the middle of the vote doesn't change the rules
+        ns.proposal_min_voting_power_for_quorum = 5000;
+        ns.proposal_min_pass_pct = 80;
+        assert!(proposal.has_quorum(&ns));
+        assert!(proposal.has_passed(&ns));
+
+        // older proposals without the snapshot still use the namespace
+        proposal.min_voting_power_for_quorum = 0;
+        proposal.min_pass_pct = 0;
+        assert!(!proposal.has_quorum(&ns));
+        ns.proposal_min_voting_power_for_quorum = 100;
+        assert!(!proposal.has_passed(&ns));
+    }
+
+    #[test]
+    fn test_proposal_finalize() {
+        let mut ns = Namespace {
+            override_now: 50,
+            lockup_default_target_voting_pct: 5000,
+            lockup_min_duration: 86400,
+            lockup_min_amount: 1000,
+            lockup_max_saturation: 86400,
+            proposal_min_voting_power_for_quorum: 100,
+            proposal_can_update_after_votes: true,
+            lockup_amount: 10000,
+            ..test_ns()
+        };
+        let mut proposal = Proposal {
+            end_ts: 100,
+            ..test_proposal()
+        };
+        assert_eq!(proposal.current_status(&ns), PROPOSAL_STATUS_ACTIVE);
+
+        // (voting power of the choices, expected status, expected winning choice)
+        let test_cases = vec![
+            ([50, 0, 0, 0, 0, 0], PROPOSAL_STATUS_QUORUM_NOT_MET, 0),
+            ([100, 10000, 0, 0, 0, 0], PROPOSAL_STATUS_PASSED, 1),
+            ([100, 100, 0, 0, 0, 0], PROPOSAL_STATUS_FAILED, 0),
+        ];
+        ns.override_now = 101;
+        for (voting_power_choices, expected_status, expected_winning_choice) in test_cases {
+            proposal.status = PROPOSAL_STATUS_DRAFT;
+            proposal.voting_power_choices = voting_power_choices;
+            proposal.finalize(&ns);
+            assert_eq!(proposal.status, expected_status);
+            assert_eq!(proposal.current_status(&ns), expected_status);
+            assert_eq!(proposal.winning_choice, expected_winning_choice);
+            assert!(proposal.is_finalized());
+            assert!(!proposal.can_update());
+        }
+
+        // cancelled and vetoed proposals stay final and are no longer active
+        ns.override_now = 50;
+        for status in [PROPOSAL_STATUS_CANCELLED, PROPOSAL_STATUS_VETOED] {
+            proposal.status = status;
+            assert!(proposal.is_finalized());
+            assert_eq!(proposal.current_status(&ns), status);
+        }
+    }
+
+    #[test]
+    fn test_proposal_change_and_revoke_vote() {
+        let mut proposal = Proposal {
+            end_ts: 100,
+            ..test_proposal()
+        };
+        proposal.cast_vote(0, 100).unwrap();
+        proposal.cast_vote(1, 50).unwrap();
+
+        // change the vote of 100 from choice 0 to choice 2 with the current voting power of 80
+        proposal.revoke_vote(0, 100).unwrap();
+        proposal.cast_vote(2, 80).unwrap();
+        assert_eq!(proposal.voting_power_choices, [0, 50, 80, 0, 0, 0]);
+
+        proposal.revoke_vote(1, 50).unwrap();
+        assert_eq!(proposal.voting_power_choices, [0, 0, 80, 0, 0, 0]);
+        assert_eq!(proposal.total_voting_power(), 80);
+
+        assert!(proposal.cast_vote(MAX_VOTING_CHOICES as u8, 10).is_err());
+        assert!(proposal.revoke_vote(MAX_VOTING_CHOICES as u8, 10).is_err());
+    }
+
+    #[test]
+    fn test_split_vote() {
+        let mut proposal = Proposal {
+            end_ts: 100,
+            ..test_proposal()
+        };
+        let mut vote_record = VoteRecordV2 {
+            ns: Pubkey::new_from_array([0; 32]),
+            owner: Pubkey::new_from_array([0; 32]),
+            proposal: Pubkey::new_from_array([0; 32]),
+            lockup: Pubkey::new_from_array([0; 32]),
+            choice: 1,
+            voting_power: 1001,
+            delegate: Pubkey::new_from_array([0; 32]),
+            lockups: [Pubkey::new_from_array([0; 32]); MAX_VOTE_LOCKUPS],
+            choice_weights_pct: [0; MAX_VOTING_CHOICES],
+            _padding: [0; 64],
+        };
+        assert!(!vote_record.valid());
+
+        vote_record.set_choice_weights(0, &[]);
+        assert!(vote_record.valid());
+        assert_eq!(vote_record.choice_weights_pct, [100, 0, 0, 0, 0, 0]);
+
+        // the choice of a split vote has to be its largest weight
+        vote_record.set_choice_weights(0, &[30, 0, 70]);
+        assert!(!vote_record.valid());
+        vote_record.set_choice_weights(2, &[30, 0, 70]);
+        assert!(vote_record.valid());
+        assert_eq!(vote_record.choice_weights_pct, [30, 0, 70, 0, 0, 0]);
+
+        proposal
+            .cast_split_vote(&vote_record.choice_weights_pct, 1001)
+            .unwrap();
+        assert_eq!(proposal.voting_power_choices, [300, 0, 701, 0, 0, 0]);
+        assert_eq!(proposal.status, PROPOSAL_STATUS_ACTIVE);
+        proposal
+            .revoke_split_vote(&vote_record.choice_weights_pct, 1001)
+            .unwrap();
+        assert_eq!(proposal.voting_power_choices, [0, 0, 0, 0, 0, 0]);
+
+        // ties go to the first choice with the largest weight
+        vote_record.set_choice_weights(1, &[0, 50, 50]);
+        assert!(vote_record.valid());
+        vote_record.set_choice_weights(2, &[0, 50, 50]);
+        assert!(!vote_record.valid());
+
+        // the records of the votes before the split votes have no weights
+        vote_record.choice_weights_pct = [0; MAX_VOTING_CHOICES];
+        vote_record.choice = 1;
+        assert_eq!(vote_record.cast_choice_weights_pct(), [0, 100, 0, 0, 0, 0]);
+
+        vote_record.set_choice_weights(2, &[30, 0, 60]);
+        assert!(!vote_record.valid());
+        vote_record.set_choice_weights(0, &[10, 10, 10, 10, 10, 10, 40]);
+        assert!(!vote_record.valid());
+        vote_record.set_choice_weights(6, &[]);
+        assert!(!vote_record.valid());
+    }
+
+    #[test]
+    fn test_proposal_choices() {
+        let mut proposal = Proposal {
+            end_ts: 100,
+            ..test_proposal()
+        };
+
+        // proposals created before num_choices accept all the choices
+        assert_eq!(proposal.num_choices(), MAX_VOTING_CHOICES);
+        assert!(proposal.valid_choice(5, &[]));
+        assert!(!proposal.valid_choice(6, &[]));
+
+        proposal.num_choices = 2;
+        assert!(proposal.valid());
+        assert!(proposal.valid_choice(1, &[]));
+        assert!(!proposal.valid_choice(2, &[]));
+        assert!(proposal.valid_choice(0, &[70, 30, 0]));
+        assert!(!proposal.valid_choice(0, &[70, 0, 30]));
+        assert!(proposal.cast_vote(2, 10).is_err());
+
+        assert!(proposal.set_choice_labels(&[]));
+        assert!(proposal.set_choice_labels(&["Yes".to_owned(), "No".to_owned()]));
+        assert_eq!(&proposal.choice_labels[0][..4], b"Yes\0");
+        assert_eq!(&proposal.choice_labels[1][..3], b"No\0");
+        assert!(!proposal.set_choice_labels(&["a".to_owned(), "b".to_owned(), "c".to_owned()]));
+        assert!(!proposal.set_choice_labels(&["a label longer than 16".to_owned()]));
+
+        proposal.num_choices = 7;
+        assert!(!proposal.valid());
     }
 }
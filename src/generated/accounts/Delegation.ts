@@ -0,0 +1,121 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface DelegationFields {
+  ns: PublicKey
+  lockup: PublicKey
+  owner: PublicKey
+  delegate: PublicKey
+  padding: Array<number>
+}
+
+export interface DelegationJSON {
+  ns: string
+  lockup: string
+  owner: string
+  delegate: string
+  padding: Array<number>
+}
+
+export class Delegation {
+  readonly ns: PublicKey
+  readonly lockup: PublicKey
+  readonly owner: PublicKey
+  readonly delegate: PublicKey
+  readonly padding: Array<number>
+
+  static readonly discriminator = Buffer.from([
+    237, 90, 140, 159, 124, 255, 243, 80,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("ns"),
+    borsh.publicKey("lockup"),
+    borsh.publicKey("owner"),
+    borsh.publicKey("delegate"),
+    borsh.array(borsh.u8(), 240, "padding"),
+  ])
+
+  constructor(fields: DelegationFields) {
+    this.ns = fields.ns
+    this.lockup = fields.lockup
+    this.owner = fields.owner
+    this.delegate = fields.delegate
+    this.padding = fields.padding
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Delegation | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Delegation | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Delegation {
+    if (!data.slice(0, 8).equals(Delegation.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Delegation.layout.decode(data.slice(8))
+
+    return new Delegation({
+      ns: dec.ns,
+      lockup: dec.lockup,
+      owner: dec.owner,
+      delegate: dec.delegate,
+      padding: dec.padding,
+    })
+  }
+
+  toJSON(): DelegationJSON {
+    return {
+      ns: this.ns.toString(),
+      lockup: this.lockup.toString(),
+      owner: this.owner.toString(),
+      delegate: this.delegate.toString(),
+      padding: this.padding,
+    }
+  }
+
+  static fromJSON(obj: DelegationJSON): Delegation {
+    return new Delegation({
+      ns: new PublicKey(obj.ns),
+      lockup: new PublicKey(obj.lockup),
+      owner: new PublicKey(obj.owner),
+      delegate: new PublicKey(obj.delegate),
+      padding: obj.padding,
+    })
+  }
+}
@@ -12,8 +12,20 @@ export interface LockupFields {
   endTs: BN
   targetRewardsPct: number
   targetVotingPct: number
-  padding: Array<number>
   weightedStartTs: BN
+  votedProposalEndTs: BN
+  index: number
+  perpetual: boolean
+  delegated: boolean
+  unstakeRequestedTs: BN
+  vestingAmount: BN
+  vestingStartTs: BN
+  vestingCliffTs: BN
+  vestingEndTs: BN
+  receiptMint: PublicKey
+  checkpoints: Array<types.LockupCheckpointFields>
+  checkpointCount: number
+  padding: Array<number>
 }
 
 export interface LockupJSON {
@@ -24,8 +36,20 @@ export interface LockupJSON {
   endTs: string
   targetRewardsPct: number
   targetVotingPct: number
-  padding: Array<number>
   weightedStartTs: string
+  votedProposalEndTs: string
+  index: number
+  perpetual: boolean
+  delegated: boolean
+  unstakeRequestedTs: string
+  vestingAmount: string
+  vestingStartTs: string
+  vestingCliffTs: string
+  vestingEndTs: string
+  receiptMint: string
+  checkpoints: Array<types.LockupCheckpointJSON>
+  checkpointCount: number
+  padding: Array<number>
 }
 
 export class Lockup {
@@ -36,8 +60,20 @@ export class Lockup {
   readonly endTs: BN
   readonly targetRewardsPct: number
   readonly targetVotingPct: number
-  readonly padding: Array<number>
   readonly weightedStartTs: BN
+  readonly votedProposalEndTs: BN
+  readonly index: number
+  readonly perpetual: boolean
+  readonly delegated: boolean
+  readonly unstakeRequestedTs: BN
+  readonly vestingAmount: BN
+  readonly vestingStartTs: BN
+  readonly vestingCliffTs: BN
+  readonly vestingEndTs: BN
+  readonly receiptMint: PublicKey
+  readonly checkpoints: Array<types.LockupCheckpoint>
+  readonly checkpointCount: number
+  readonly padding: Array<number>
 
   static readonly discriminator = Buffer.from([1, 45, 32, 32, 57, 81, 88, 67])
 
@@ -50,7 +86,19 @@ export class Lockup {
     borsh.u16("targetRewardsPct"),
     borsh.u16("targetVotingPct"),
     borsh.i64("weightedStartTs"),
-    borsh.array(borsh.u8(), 232, "padding"),
+    borsh.i64("votedProposalEndTs"),
+    borsh.u32("index"),
+    borsh.bool("perpetual"),
+    borsh.bool("delegated"),
+    borsh.i64("unstakeRequestedTs"),
+    borsh.u64("vestingAmount"),
+    borsh.i64("vestingStartTs"),
+    borsh.i64("vestingCliffTs"),
+    borsh.i64("vestingEndTs"),
+    borsh.publicKey("receiptMint"),
+    borsh.array(types.LockupCheckpoint.layout(), 3, "checkpoints"),
+    borsh.u32("checkpointCount"),
+    borsh.array(borsh.u8(), 13, "padding"),
   ])
 
   constructor(fields: LockupFields) {
@@ -61,8 +109,22 @@ export class Lockup {
     this.endTs = fields.endTs
     this.targetRewardsPct = fields.targetRewardsPct
     this.targetVotingPct = fields.targetVotingPct
-    this.padding = fields.padding
     this.weightedStartTs = fields.weightedStartTs
+    this.votedProposalEndTs = fields.votedProposalEndTs
+    this.index = fields.index
+    this.perpetual = fields.perpetual
+    this.delegated = fields.delegated
+    this.unstakeRequestedTs = fields.unstakeRequestedTs
+    this.vestingAmount = fields.vestingAmount
+    this.vestingStartTs = fields.vestingStartTs
+    this.vestingCliffTs = fields.vestingCliffTs
+    this.vestingEndTs = fields.vestingEndTs
+    this.receiptMint = fields.receiptMint
+    this.checkpoints = fields.checkpoints.map(
+      (item) => new types.LockupCheckpoint({ ...item })
+    )
+    this.checkpointCount = fields.checkpointCount
+    this.padding = fields.padding
   }
 
   static async fetch(
@@ -116,8 +178,24 @@ export class Lockup {
       endTs: dec.endTs,
       targetRewardsPct: dec.targetRewardsPct,
       targetVotingPct: dec.targetVotingPct,
-      padding: dec.padding,
       weightedStartTs: dec.weightedStartTs,
+      votedProposalEndTs: dec.votedProposalEndTs,
+      index: dec.index,
+      perpetual: dec.perpetual,
+      delegated: dec.delegated,
+      unstakeRequestedTs: dec.unstakeRequestedTs,
+      vestingAmount: dec.vestingAmount,
+      vestingStartTs: dec.vestingStartTs,
+      vestingCliffTs: dec.vestingCliffTs,
+      vestingEndTs: dec.vestingEndTs,
+      receiptMint: dec.receiptMint,
+      checkpoints: dec.checkpoints.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.LockupCheckpoint.fromDecoded(item)
+      ),
+      checkpointCount: dec.checkpointCount,
+      padding: dec.padding,
     })
   }
 
@@ -130,8 +208,20 @@ export class Lockup {
       endTs: this.endTs.toString(),
       targetRewardsPct: this.targetRewardsPct,
       targetVotingPct: this.targetVotingPct,
-      padding: this.padding,
       weightedStartTs: this.weightedStartTs.toString(),
+      votedProposalEndTs: this.votedProposalEndTs.toString(),
+      index: this.index,
+      perpetual: this.perpetual,
+      delegated: this.delegated,
+      unstakeRequestedTs: this.unstakeRequestedTs.toString(),
+      vestingAmount: this.vestingAmount.toString(),
+      vestingStartTs: this.vestingStartTs.toString(),
+      vestingCliffTs: this.vestingCliffTs.toString(),
+      vestingEndTs: this.vestingEndTs.toString(),
+      receiptMint: this.receiptMint.toString(),
+      checkpoints: this.checkpoints.map((item) => item.toJSON()),
+      checkpointCount: this.checkpointCount,
+      padding: this.padding,
     }
   }
 
@@ -144,8 +234,22 @@ export class Lockup {
       endTs: new BN(obj.endTs),
       targetRewardsPct: obj.targetRewardsPct,
       targetVotingPct: obj.targetVotingPct,
-      padding: obj.padding,
       weightedStartTs: new BN(obj.weightedStartTs),
+      votedProposalEndTs: new BN(obj.votedProposalEndTs),
+      index: obj.index,
+      perpetual: obj.perpetual,
+      delegated: obj.delegated,
+      unstakeRequestedTs: new BN(obj.unstakeRequestedTs),
+      vestingAmount: new BN(obj.vestingAmount),
+      vestingStartTs: new BN(obj.vestingStartTs),
+      vestingCliffTs: new BN(obj.vestingCliffTs),
+      vestingEndTs: new BN(obj.vestingEndTs),
+      receiptMint: new PublicKey(obj.receiptMint),
+      checkpoints: obj.checkpoints.map((item) =>
+        types.LockupCheckpoint.fromJSON(item)
+      ),
+      checkpointCount: obj.checkpointCount,
+      padding: obj.padding,
     })
   }
 }
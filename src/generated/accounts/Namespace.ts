@@ -20,6 +20,12 @@ export interface NamespaceFields {
   proposalCanUpdateAfterVotes: boolean
   lockupAmount: BN
   proposalNonce: number
+  lockupEarlyUnlockPenaltyPct: number
+  lockupVotingPowerMode: number
+  lockupUnstakeCooldown: BN
+  emergencyUnlock: boolean
+  lockupVotingCurveLen: number
+  lockupVotingCurve: Array<types.VotingCurvePointFields>
   padding: Array<number>
 }
 
@@ -39,6 +45,12 @@ export interface NamespaceJSON {
   proposalCanUpdateAfterVotes: boolean
   lockupAmount: string
   proposalNonce: number
+  lockupEarlyUnlockPenaltyPct: number
+  lockupVotingPowerMode: number
+  lockupUnstakeCooldown: string
+  emergencyUnlock: boolean
+  lockupVotingCurveLen: number
+  lockupVotingCurve: Array<types.VotingCurvePointJSON>
   padding: Array<number>
 }
 
@@ -58,6 +70,12 @@ export class Namespace {
   readonly proposalCanUpdateAfterVotes: boolean
   readonly lockupAmount: BN
   readonly proposalNonce: number
+  readonly lockupEarlyUnlockPenaltyPct: number
+  readonly lockupVotingPowerMode: number
+  readonly lockupUnstakeCooldown: BN
+  readonly emergencyUnlock: boolean
+  readonly lockupVotingCurveLen: number
+  readonly lockupVotingCurve: Array<types.VotingCurvePoint>
   readonly padding: Array<number>
 
   static readonly discriminator = Buffer.from([
@@ -80,7 +98,13 @@ export class Namespace {
     borsh.bool("proposalCanUpdateAfterVotes"),
     borsh.u64("lockupAmount"),
     borsh.u32("proposalNonce"),
-    borsh.array(borsh.u8(), 240, "padding"),
+    borsh.u16("lockupEarlyUnlockPenaltyPct"),
+    borsh.u8("lockupVotingPowerMode"),
+    borsh.i64("lockupUnstakeCooldown"),
+    borsh.bool("emergencyUnlock"),
+    borsh.u8("lockupVotingCurveLen"),
+    borsh.array(types.VotingCurvePoint.layout(), 8, "lockupVotingCurve"),
+    borsh.array(borsh.u8(), 147, "padding"),
   ])
 
   constructor(fields: NamespaceFields) {
@@ -100,6 +124,14 @@ export class Namespace {
     this.proposalCanUpdateAfterVotes = fields.proposalCanUpdateAfterVotes
     this.lockupAmount = fields.lockupAmount
     this.proposalNonce = fields.proposalNonce
+    this.lockupEarlyUnlockPenaltyPct = fields.lockupEarlyUnlockPenaltyPct
+    this.lockupVotingPowerMode = fields.lockupVotingPowerMode
+    this.lockupUnstakeCooldown = fields.lockupUnstakeCooldown
+    this.emergencyUnlock = fields.emergencyUnlock
+    this.lockupVotingCurveLen = fields.lockupVotingCurveLen
+    this.lockupVotingCurve = fields.lockupVotingCurve.map(
+      (item) => new types.VotingCurvePoint({ ...item })
+    )
     this.padding = fields.padding
   }
 
@@ -162,6 +194,16 @@ export class Namespace {
       proposalCanUpdateAfterVotes: dec.proposalCanUpdateAfterVotes,
       lockupAmount: dec.lockupAmount,
       proposalNonce: dec.proposalNonce,
+      lockupEarlyUnlockPenaltyPct: dec.lockupEarlyUnlockPenaltyPct,
+      lockupVotingPowerMode: dec.lockupVotingPowerMode,
+      lockupUnstakeCooldown: dec.lockupUnstakeCooldown,
+      emergencyUnlock: dec.emergencyUnlock,
+      lockupVotingCurveLen: dec.lockupVotingCurveLen,
+      lockupVotingCurve: dec.lockupVotingCurve.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.VotingCurvePoint.fromDecoded(item)
+      ),
       padding: dec.padding,
     })
   }
@@ -184,6 +226,12 @@ export class Namespace {
       proposalCanUpdateAfterVotes: this.proposalCanUpdateAfterVotes,
       lockupAmount: this.lockupAmount.toString(),
       proposalNonce: this.proposalNonce,
+      lockupEarlyUnlockPenaltyPct: this.lockupEarlyUnlockPenaltyPct,
+      lockupVotingPowerMode: this.lockupVotingPowerMode,
+      lockupUnstakeCooldown: this.lockupUnstakeCooldown.toString(),
+      emergencyUnlock: this.emergencyUnlock,
+      lockupVotingCurveLen: this.lockupVotingCurveLen,
+      lockupVotingCurve: this.lockupVotingCurve.map((item) => item.toJSON()),
       padding: this.padding,
     }
   }
@@ -207,6 +255,14 @@ export class Namespace {
       proposalCanUpdateAfterVotes: obj.proposalCanUpdateAfterVotes,
       lockupAmount: new BN(obj.lockupAmount),
       proposalNonce: obj.proposalNonce,
+      lockupEarlyUnlockPenaltyPct: obj.lockupEarlyUnlockPenaltyPct,
+      lockupVotingPowerMode: obj.lockupVotingPowerMode,
+      lockupUnstakeCooldown: new BN(obj.lockupUnstakeCooldown),
+      emergencyUnlock: obj.emergencyUnlock,
+      lockupVotingCurveLen: obj.lockupVotingCurveLen,
+      lockupVotingCurve: obj.lockupVotingCurve.map((item) =>
+        types.VotingCurvePoint.fromJSON(item)
+      ),
       padding: obj.padding,
     })
   }
@@ -13,6 +13,11 @@ export interface ProposalFields {
   status: number
   votingPowerChoices: Array<BN>
   uri: string
+  winningChoice: number
+  numChoices: number
+  choiceLabels: Array<Array<number>>
+  minVotingPowerForQuorum: BN
+  minPassPct: number
   padding: Array<number>
 }
 
@@ -25,6 +30,11 @@ export interface ProposalJSON {
   status: number
   votingPowerChoices: Array<string>
   uri: string
+  winningChoice: number
+  numChoices: number
+  choiceLabels: Array<Array<number>>
+  minVotingPowerForQuorum: string
+  minPassPct: number
   padding: Array<number>
 }
 
@@ -37,6 +47,11 @@ export class Proposal {
   readonly status: number
   readonly votingPowerChoices: Array<BN>
   readonly uri: string
+  readonly winningChoice: number
+  readonly numChoices: number
+  readonly choiceLabels: Array<Array<number>>
+  readonly minVotingPowerForQuorum: BN
+  readonly minPassPct: number
   readonly padding: Array<number>
 
   static readonly discriminator = Buffer.from([
@@ -52,7 +67,12 @@ export class Proposal {
     borsh.u8("status"),
     borsh.array(borsh.u64(), 6, "votingPowerChoices"),
     borsh.str("uri"),
-    borsh.array(borsh.u8(), 240, "padding"),
+    borsh.u8("winningChoice"),
+    borsh.u8("numChoices"),
+    borsh.array(borsh.array(borsh.u8(), 16), 6, "choiceLabels"),
+    borsh.u64("minVotingPowerForQuorum"),
+    borsh.u16("minPassPct"),
+    borsh.array(borsh.u8(), 132, "padding"),
   ])
 
   constructor(fields: ProposalFields) {
@@ -64,6 +84,11 @@ export class Proposal {
     this.status = fields.status
     this.votingPowerChoices = fields.votingPowerChoices
     this.uri = fields.uri
+    this.winningChoice = fields.winningChoice
+    this.numChoices = fields.numChoices
+    this.choiceLabels = fields.choiceLabels
+    this.minVotingPowerForQuorum = fields.minVotingPowerForQuorum
+    this.minPassPct = fields.minPassPct
     this.padding = fields.padding
   }
 
@@ -119,6 +144,11 @@ export class Proposal {
       status: dec.status,
       votingPowerChoices: dec.votingPowerChoices,
       uri: dec.uri,
+      winningChoice: dec.winningChoice,
+      numChoices: dec.numChoices,
+      choiceLabels: dec.choiceLabels,
+      minVotingPowerForQuorum: dec.minVotingPowerForQuorum,
+      minPassPct: dec.minPassPct,
       padding: dec.padding,
     })
   }
@@ -135,6 +165,11 @@ export class Proposal {
         item.toString()
       ),
       uri: this.uri,
+      winningChoice: this.winningChoice,
+      numChoices: this.numChoices,
+      choiceLabels: this.choiceLabels,
+      minVotingPowerForQuorum: this.minVotingPowerForQuorum.toString(),
+      minPassPct: this.minPassPct,
       padding: this.padding,
     }
   }
@@ -149,6 +184,11 @@ export class Proposal {
       status: obj.status,
       votingPowerChoices: obj.votingPowerChoices.map((item) => new BN(item)),
       uri: obj.uri,
+      winningChoice: obj.winningChoice,
+      numChoices: obj.numChoices,
+      choiceLabels: obj.choiceLabels,
+      minVotingPowerForQuorum: new BN(obj.minVotingPowerForQuorum),
+      minPassPct: obj.minPassPct,
       padding: obj.padding,
     })
   }
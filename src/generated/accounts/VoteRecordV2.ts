@@ -0,0 +1,161 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VoteRecordV2Fields {
+  ns: PublicKey
+  owner: PublicKey
+  proposal: PublicKey
+  lockup: PublicKey
+  choice: number
+  votingPower: BN
+  delegate: PublicKey
+  lockups: Array<PublicKey>
+  choiceWeightsPct: Array<number>
+  padding: Array<number>
+}
+
+export interface VoteRecordV2JSON {
+  ns: string
+  owner: string
+  proposal: string
+  lockup: string
+  choice: number
+  votingPower: string
+  delegate: string
+  lockups: Array<string>
+  choiceWeightsPct: Array<number>
+  padding: Array<number>
+}
+
+export class VoteRecordV2 {
+  readonly ns: PublicKey
+  readonly owner: PublicKey
+  readonly proposal: PublicKey
+  readonly lockup: PublicKey
+  readonly choice: number
+  readonly votingPower: BN
+  readonly delegate: PublicKey
+  readonly lockups: Array<PublicKey>
+  readonly choiceWeightsPct: Array<number>
+  readonly padding: Array<number>
+
+  static readonly discriminator = Buffer.from([
+    203, 12, 118, 185, 180, 116, 242, 147,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("ns"),
+    borsh.publicKey("owner"),
+    borsh.publicKey("proposal"),
+    borsh.publicKey("lockup"),
+    borsh.u8("choice"),
+    borsh.u64("votingPower"),
+    borsh.publicKey("delegate"),
+    borsh.array(borsh.publicKey(), 8, "lockups"),
+    borsh.array(borsh.u8(), 6, "choiceWeightsPct"),
+    borsh.array(borsh.u8(), 64, "padding"),
+  ])
+
+  constructor(fields: VoteRecordV2Fields) {
+    this.ns = fields.ns
+    this.owner = fields.owner
+    this.proposal = fields.proposal
+    this.lockup = fields.lockup
+    this.choice = fields.choice
+    this.votingPower = fields.votingPower
+    this.delegate = fields.delegate
+    this.lockups = fields.lockups
+    this.choiceWeightsPct = fields.choiceWeightsPct
+    this.padding = fields.padding
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<VoteRecordV2 | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<VoteRecordV2 | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): VoteRecordV2 {
+    if (!data.slice(0, 8).equals(VoteRecordV2.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = VoteRecordV2.layout.decode(data.slice(8))
+
+    return new VoteRecordV2({
+      ns: dec.ns,
+      owner: dec.owner,
+      proposal: dec.proposal,
+      lockup: dec.lockup,
+      choice: dec.choice,
+      votingPower: dec.votingPower,
+      delegate: dec.delegate,
+      lockups: dec.lockups,
+      choiceWeightsPct: dec.choiceWeightsPct,
+      padding: dec.padding,
+    })
+  }
+
+  toJSON(): VoteRecordV2JSON {
+    return {
+      ns: this.ns.toString(),
+      owner: this.owner.toString(),
+      proposal: this.proposal.toString(),
+      lockup: this.lockup.toString(),
+      choice: this.choice,
+      votingPower: this.votingPower.toString(),
+      delegate: this.delegate.toString(),
+      lockups: this.lockups.map((item) => item.toString()),
+      choiceWeightsPct: this.choiceWeightsPct,
+      padding: this.padding,
+    }
+  }
+
+  static fromJSON(obj: VoteRecordV2JSON): VoteRecordV2 {
+    return new VoteRecordV2({
+      ns: new PublicKey(obj.ns),
+      owner: new PublicKey(obj.owner),
+      proposal: new PublicKey(obj.proposal),
+      lockup: new PublicKey(obj.lockup),
+      choice: obj.choice,
+      votingPower: new BN(obj.votingPower),
+      delegate: new PublicKey(obj.delegate),
+      lockups: obj.lockups.map((item) => new PublicKey(item)),
+      choiceWeightsPct: obj.choiceWeightsPct,
+      padding: obj.padding,
+    })
+  }
+}
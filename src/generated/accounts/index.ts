@@ -6,6 +6,10 @@ export { Proposal } from "./Proposal"
 export type { ProposalFields, ProposalJSON } from "./Proposal"
 export { VoteRecord } from "./VoteRecord"
 export type { VoteRecordFields, VoteRecordJSON } from "./VoteRecord"
+export { VoteRecordV2 } from "./VoteRecordV2"
+export type { VoteRecordV2Fields, VoteRecordV2JSON } from "./VoteRecordV2"
+export { Delegation } from "./Delegation"
+export type { DelegationFields, DelegationJSON } from "./Delegation"
 export { Distribution } from "./Distribution"
 export type { DistributionFields, DistributionJSON } from "./Distribution"
 export { DistributionClaim } from "./DistributionClaim"
@@ -14,6 +14,17 @@ export type CustomError =
   | InvalidNamespace
   | InvalidLockup
   | InvalidVoteRecord
+  | EarlyUnlockDisabled
+  | LockupHasActiveVotes
+  | LockupDelegated
+  | InvalidDelegation
+  | UnstakeCooldownNotEnded
+  | EmergencyUnlocked
+  | LockupVesting
+  | LockupWithoutEndTs
+  | LockupHasReceipt
+  | InvalidReceipt
+  | InvalidChoice
 
 export class InvalidOwner extends Error {
   static readonly code = 6000
@@ -180,6 +191,127 @@ export class InvalidVoteRecord extends Error {
   }
 }
 
+export class EarlyUnlockDisabled extends Error {
+  static readonly code = 6015
+  readonly code = 6015
+  readonly name = "EarlyUnlockDisabled"
+  readonly msg = "Early Unlock Disabled"
+
+  constructor(readonly logs?: string[]) {
+    super("6015: Early Unlock Disabled")
+  }
+}
+
+export class LockupHasActiveVotes extends Error {
+  static readonly code = 6016
+  readonly code = 6016
+  readonly name = "LockupHasActiveVotes"
+  readonly msg = "Lockup Has Active Votes"
+
+  constructor(readonly logs?: string[]) {
+    super("6016: Lockup Has Active Votes")
+  }
+}
+
+export class LockupDelegated extends Error {
+  static readonly code = 6017
+  readonly code = 6017
+  readonly name = "LockupDelegated"
+  readonly msg = "Lockup Delegated"
+
+  constructor(readonly logs?: string[]) {
+    super("6017: Lockup Delegated")
+  }
+}
+
+export class InvalidDelegation extends Error {
+  static readonly code = 6018
+  readonly code = 6018
+  readonly name = "InvalidDelegation"
+  readonly msg = "Invalid Delegation"
+
+  constructor(readonly logs?: string[]) {
+    super("6018: Invalid Delegation")
+  }
+}
+
+export class UnstakeCooldownNotEnded extends Error {
+  static readonly code = 6019
+  readonly code = 6019
+  readonly name = "UnstakeCooldownNotEnded"
+  readonly msg = "Unstake Cooldown Not Ended"
+
+  constructor(readonly logs?: string[]) {
+    super("6019: Unstake Cooldown Not Ended")
+  }
+}
+
+export class EmergencyUnlocked extends Error {
+  static readonly code = 6020
+  readonly code = 6020
+  readonly name = "EmergencyUnlocked"
+  readonly msg = "Emergency Unlocked"
+
+  constructor(readonly logs?: string[]) {
+    super("6020: Emergency Unlocked")
+  }
+}
+
+export class LockupVesting extends Error {
+  static readonly code = 6021
+  readonly code = 6021
+  readonly name = "LockupVesting"
+  readonly msg = "Lockup Vesting"
+
+  constructor(readonly logs?: string[]) {
+    super("6021: Lockup Vesting")
+  }
+}
+
+export class LockupWithoutEndTs extends Error {
+  static readonly code = 6022
+  readonly code = 6022
+  readonly name = "LockupWithoutEndTs"
+  readonly msg = "Lockup Without End Ts"
+
+  constructor(readonly logs?: string[]) {
+    super("6022: Lockup Without End Ts")
+  }
+}
+
+export class LockupHasReceipt extends Error {
+  static readonly code = 6023
+  readonly code = 6023
+  readonly name = "LockupHasReceipt"
+  readonly msg = "Lockup Has Receipt"
+
+  constructor(readonly logs?: string[]) {
+    super("6023: Lockup Has Receipt")
+  }
+}
+
+export class InvalidReceipt extends Error {
+  static readonly code = 6024
+  readonly code = 6024
+  readonly name = "InvalidReceipt"
+  readonly msg = "Invalid Receipt"
+
+  constructor(readonly logs?: string[]) {
+    super("6024: Invalid Receipt")
+  }
+}
+
+export class InvalidChoice extends Error {
+  static readonly code = 6025
+  readonly code = 6025
+  readonly name = "InvalidChoice"
+  readonly msg = "Invalid Choice"
+
+  constructor(readonly logs?: string[]) {
+    super("6025: Invalid Choice")
+  }
+}
+
 export function fromCode(code: number, logs?: string[]): CustomError | null {
   switch (code) {
     case 6000:
@@ -212,6 +344,28 @@ export function fromCode(code: number, logs?: string[]): CustomError | null {
       return new InvalidLockup(logs)
     case 6014:
       return new InvalidVoteRecord(logs)
+    case 6015:
+      return new EarlyUnlockDisabled(logs)
+    case 6016:
+      return new LockupHasActiveVotes(logs)
+    case 6017:
+      return new LockupDelegated(logs)
+    case 6018:
+      return new InvalidDelegation(logs)
+    case 6019:
+      return new UnstakeCooldownNotEnded(logs)
+    case 6020:
+      return new EmergencyUnlocked(logs)
+    case 6021:
+      return new LockupVesting(logs)
+    case 6022:
+      return new LockupWithoutEndTs(logs)
+    case 6023:
+      return new LockupHasReceipt(logs)
+    case 6024:
+      return new InvalidReceipt(logs)
+    case 6025:
+      return new InvalidChoice(logs)
   }
 
   return null
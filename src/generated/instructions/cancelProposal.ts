@@ -0,0 +1,26 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CancelProposalAccounts {
+  reviewCouncil: PublicKey
+  proposal: PublicKey
+  ns: PublicKey
+}
+
+export function cancelProposal(
+  accounts: CancelProposalAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.reviewCouncil, isSigner: true, isWritable: false },
+    { pubkey: accounts.proposal, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([106, 74, 128, 146, 19, 65, 39, 23])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ChangeVoteArgs {
+  args: types.ChangeVoteArgsFields
+}
+
+export interface ChangeVoteAccounts {
+  owner: PublicKey
+  proposal: PublicKey
+  lockup: PublicKey
+  voteRecord: PublicKey
+  ns: PublicKey
+}
+
+export const layout = borsh.struct([types.ChangeVoteArgs.layout("args")])
+
+export function changeVote(
+  args: ChangeVoteArgs,
+  accounts: ChangeVoteAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.proposal, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.voteRecord, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([184, 39, 97, 137, 83, 108, 185, 75])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.ChangeVoteArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
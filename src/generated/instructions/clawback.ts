@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClawbackAccounts {
+  securityCouncil: PublicKey
+  owner: PublicKey
+  tokenMint: PublicKey
+  tokenAccount: PublicKey
+  lockup: PublicKey
+  lockupTokenAccount: PublicKey
+  delegation: PublicKey
+  ns: PublicKey
+  tokenProgram: PublicKey
+  systemProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export function clawback(
+  accounts: ClawbackAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.securityCouncil, isSigner: true, isWritable: true },
+    { pubkey: accounts.owner, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockupTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.delegation, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([111, 92, 142, 79, 33, 234, 82, 27])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,32 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface DelegateLockupAccounts {
+  owner: PublicKey
+  delegate: PublicKey
+  lockup: PublicKey
+  delegation: PublicKey
+  ns: PublicKey
+  systemProgram: PublicKey
+}
+
+export function delegateLockup(
+  accounts: DelegateLockupAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.delegate, isSigner: false, isWritable: false },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.delegation, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([127, 68, 107, 26, 34, 133, 50, 92])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
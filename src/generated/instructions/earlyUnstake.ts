@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface EarlyUnstakeAccounts {
+  owner: PublicKey
+  tokenMint: PublicKey
+  tokenAccount: PublicKey
+  lockup: PublicKey
+  lockupTokenAccount: PublicKey
+  penaltyTokenAccount: PublicKey
+  ns: PublicKey
+  tokenProgram: PublicKey
+  systemProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export function earlyUnstake(
+  accounts: EarlyUnstakeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.tokenMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockupTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.penaltyTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([8, 122, 95, 239, 95, 84, 132, 43])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,24 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FinalizeProposalAccounts {
+  proposal: PublicKey
+  ns: PublicKey
+}
+
+export function finalizeProposal(
+  accounts: FinalizeProposalAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.proposal, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([23, 68, 51, 167, 109, 173, 187, 164])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -5,12 +5,66 @@ export type {
   UpdateNamespaceArgs,
   UpdateNamespaceAccounts,
 } from "./updateNamespace"
+export { setEmergencyUnlock } from "./setEmergencyUnlock"
+export type {
+  SetEmergencyUnlockArgs,
+  SetEmergencyUnlockAccounts,
+} from "./setEmergencyUnlock"
 export { stake } from "./stake"
 export type { StakeArgs, StakeAccounts } from "./stake"
+export { relock } from "./relock"
+export type { RelockArgs, RelockAccounts } from "./relock"
 export { stakeTo } from "./stakeTo"
 export type { StakeToArgs, StakeToAccounts } from "./stakeTo"
+export { stakeFor } from "./stakeFor"
+export type { StakeForArgs, StakeForAccounts } from "./stakeFor"
+export { stakeToVesting } from "./stakeToVesting"
+export type {
+  StakeToVestingArgs,
+  StakeToVestingAccounts,
+} from "./stakeToVesting"
+export { clawback } from "./clawback"
+export type { ClawbackAccounts } from "./clawback"
+export { requestUnstake } from "./requestUnstake"
+export type { RequestUnstakeAccounts } from "./requestUnstake"
 export { unstake } from "./unstake"
 export type { UnstakeAccounts } from "./unstake"
+export { unstakePartial } from "./unstakePartial"
+export type {
+  UnstakePartialArgs,
+  UnstakePartialAccounts,
+} from "./unstakePartial"
+export { earlyUnstake } from "./earlyUnstake"
+export type { EarlyUnstakeAccounts } from "./earlyUnstake"
+export { withdrawPenalty } from "./withdrawPenalty"
+export type { WithdrawPenaltyAccounts } from "./withdrawPenalty"
+export { transferLockup } from "./transferLockup"
+export type {
+  TransferLockupArgs,
+  TransferLockupAccounts,
+} from "./transferLockup"
+export { mergeLockups } from "./mergeLockups"
+export type { MergeLockupsAccounts } from "./mergeLockups"
+export { splitLockup } from "./splitLockup"
+export type { SplitLockupArgs, SplitLockupAccounts } from "./splitLockup"
+export { setLockupPerpetual } from "./setLockupPerpetual"
+export type {
+  SetLockupPerpetualArgs,
+  SetLockupPerpetualAccounts,
+} from "./setLockupPerpetual"
+export { setLockupMultipliers } from "./setLockupMultipliers"
+export type {
+  SetLockupMultipliersArgs,
+  SetLockupMultipliersAccounts,
+} from "./setLockupMultipliers"
+export { mintLockupReceipt } from "./mintLockupReceipt"
+export type { MintLockupReceiptAccounts } from "./mintLockupReceipt"
+export { refreshLockupReceipt } from "./refreshLockupReceipt"
+export type { RefreshLockupReceiptAccounts } from "./refreshLockupReceipt"
+export { requestUnstakeWithReceipt } from "./requestUnstakeWithReceipt"
+export type { RequestUnstakeWithReceiptAccounts } from "./requestUnstakeWithReceipt"
+export { unstakeWithReceipt } from "./unstakeWithReceipt"
+export type { UnstakeWithReceiptAccounts } from "./unstakeWithReceipt"
 export { initProposal } from "./initProposal"
 export type { InitProposalArgs, InitProposalAccounts } from "./initProposal"
 export { updateProposal } from "./updateProposal"
@@ -18,8 +72,29 @@ export type {
   UpdateProposalArgs,
   UpdateProposalAccounts,
 } from "./updateProposal"
+export { cancelProposal } from "./cancelProposal"
+export type { CancelProposalAccounts } from "./cancelProposal"
+export { vetoProposal } from "./vetoProposal"
+export type { VetoProposalAccounts } from "./vetoProposal"
+export { finalizeProposal } from "./finalizeProposal"
+export type { FinalizeProposalAccounts } from "./finalizeProposal"
 export { vote } from "./vote"
 export type { VoteArgs, VoteAccounts } from "./vote"
+export { changeVote } from "./changeVote"
+export type { ChangeVoteArgs, ChangeVoteAccounts } from "./changeVote"
+export { revokeVote } from "./revokeVote"
+export type { RevokeVoteAccounts } from "./revokeVote"
+export { delegateLockup } from "./delegateLockup"
+export type { DelegateLockupAccounts } from "./delegateLockup"
+export { revokeDelegation } from "./revokeDelegation"
+export type { RevokeDelegationAccounts } from "./revokeDelegation"
+export { voteDelegated } from "./voteDelegated"
+export type { VoteDelegatedArgs, VoteDelegatedAccounts } from "./voteDelegated"
+export { voteWithReceipt } from "./voteWithReceipt"
+export type {
+  VoteWithReceiptArgs,
+  VoteWithReceiptAccounts,
+} from "./voteWithReceipt"
 export { initDistribution } from "./initDistribution"
 export type {
   InitDistributionArgs,
@@ -0,0 +1,48 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MergeLockupsAccounts {
+  owner: PublicKey
+  tokenMint: PublicKey
+  lockup: PublicKey
+  lockupTokenAccount: PublicKey
+  sourceLockup: PublicKey
+  sourceLockupTokenAccount: PublicKey
+  ns: PublicKey
+  tokenProgram: PublicKey
+  systemProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export function mergeLockups(
+  accounts: MergeLockupsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.tokenMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockupTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.sourceLockup, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.sourceLockupTokenAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([16, 156, 50, 45, 116, 82, 227, 140])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
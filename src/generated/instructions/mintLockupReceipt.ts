@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MintLockupReceiptAccounts {
+  owner: PublicKey
+  lockup: PublicKey
+  receiptMint: PublicKey
+  receiptAuthority: PublicKey
+  receiptTokenAccount: PublicKey
+  ns: PublicKey
+  receiptTokenProgram: PublicKey
+  systemProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export function mintLockupReceipt(
+  accounts: MintLockupReceiptAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.receiptMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.receiptAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.receiptTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.receiptTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([147, 119, 104, 221, 156, 239, 57, 148])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
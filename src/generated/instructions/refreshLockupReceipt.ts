@@ -0,0 +1,38 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RefreshLockupReceiptAccounts {
+  payer: PublicKey
+  lockup: PublicKey
+  receiptMint: PublicKey
+  receiptAuthority: PublicKey
+  ns: PublicKey
+  receiptTokenProgram: PublicKey
+  systemProgram: PublicKey
+}
+
+export function refreshLockupReceipt(
+  accounts: RefreshLockupReceiptAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: false },
+    { pubkey: accounts.receiptMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.receiptAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.receiptTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([123, 181, 234, 228, 72, 150, 195, 239])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
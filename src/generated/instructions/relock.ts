@@ -0,0 +1,56 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RelockArgs {
+  args: types.RelockArgsFields
+}
+
+export interface RelockAccounts {
+  owner: PublicKey
+  tokenMint: PublicKey
+  tokenAccount: PublicKey
+  lockup: PublicKey
+  lockupTokenAccount: PublicKey
+  ns: PublicKey
+  tokenProgram: PublicKey
+  systemProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.RelockArgs.layout("args")])
+
+export function relock(
+  args: RelockArgs,
+  accounts: RelockAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.tokenMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockupTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([235, 187, 184, 112, 143, 63, 95, 102])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.RelockArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
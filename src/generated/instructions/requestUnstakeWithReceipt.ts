@@ -0,0 +1,32 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RequestUnstakeWithReceiptAccounts {
+  holder: PublicKey
+  receiptTokenAccount: PublicKey
+  lockup: PublicKey
+  ns: PublicKey
+}
+
+export function requestUnstakeWithReceipt(
+  accounts: RequestUnstakeWithReceiptAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.holder, isSigner: true, isWritable: false },
+    {
+      pubkey: accounts.receiptTokenAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([251, 189, 232, 208, 98, 118, 207, 20])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,40 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetEmergencyUnlockArgs {
+  args: types.SetEmergencyUnlockArgsFields
+}
+
+export interface SetEmergencyUnlockAccounts {
+  securityCouncil: PublicKey
+  ns: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.SetEmergencyUnlockArgs.layout("args"),
+])
+
+export function setEmergencyUnlock(
+  args: SetEmergencyUnlockArgs,
+  accounts: SetEmergencyUnlockAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.securityCouncil, isSigner: true, isWritable: false },
+    { pubkey: accounts.ns, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([222, 188, 51, 221, 118, 77, 161, 133])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.SetEmergencyUnlockArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,42 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetLockupMultipliersArgs {
+  args: types.SetLockupMultipliersArgsFields
+}
+
+export interface SetLockupMultipliersAccounts {
+  securityCouncil: PublicKey
+  lockup: PublicKey
+  ns: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.SetLockupMultipliersArgs.layout("args"),
+])
+
+export function setLockupMultipliers(
+  args: SetLockupMultipliersArgs,
+  accounts: SetLockupMultipliersAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.securityCouncil, isSigner: true, isWritable: false },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([15, 129, 163, 167, 241, 119, 106, 89])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.SetLockupMultipliersArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
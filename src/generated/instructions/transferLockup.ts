@@ -0,0 +1,64 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface TransferLockupArgs {
+  args: types.TransferLockupArgsFields
+}
+
+export interface TransferLockupAccounts {
+  owner: PublicKey
+  newOwner: PublicKey
+  tokenMint: PublicKey
+  lockup: PublicKey
+  lockupTokenAccount: PublicKey
+  newLockup: PublicKey
+  newLockupTokenAccount: PublicKey
+  ns: PublicKey
+  tokenProgram: PublicKey
+  systemProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.TransferLockupArgs.layout("args")])
+
+export function transferLockup(
+  args: TransferLockupArgs,
+  accounts: TransferLockupAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.newOwner, isSigner: true, isWritable: false },
+    { pubkey: accounts.tokenMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockupTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.newLockup, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.newLockupTokenAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([32, 106, 91, 144, 55, 197, 190, 243])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.TransferLockupArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
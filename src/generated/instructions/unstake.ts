@@ -10,6 +10,7 @@ export interface UnstakeAccounts {
   tokenAccount: PublicKey
   lockup: PublicKey
   lockupTokenAccount: PublicKey
+  delegation: PublicKey
   ns: PublicKey
   tokenProgram: PublicKey
   systemProgram: PublicKey
@@ -26,6 +27,7 @@ export function unstake(
     { pubkey: accounts.tokenAccount, isSigner: false, isWritable: true },
     { pubkey: accounts.lockup, isSigner: false, isWritable: true },
     { pubkey: accounts.lockupTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.delegation, isSigner: false, isWritable: true },
     { pubkey: accounts.ns, isSigner: false, isWritable: true },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
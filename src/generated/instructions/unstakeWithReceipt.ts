@@ -0,0 +1,56 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UnstakeWithReceiptAccounts {
+  holder: PublicKey
+  owner: PublicKey
+  tokenMint: PublicKey
+  tokenAccount: PublicKey
+  receiptMint: PublicKey
+  receiptTokenAccount: PublicKey
+  receiptAuthority: PublicKey
+  lockup: PublicKey
+  lockupTokenAccount: PublicKey
+  ns: PublicKey
+  tokenProgram: PublicKey
+  receiptTokenProgram: PublicKey
+  systemProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export function unstakeWithReceipt(
+  accounts: UnstakeWithReceiptAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.holder, isSigner: true, isWritable: true },
+    { pubkey: accounts.owner, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.receiptMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.receiptTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.receiptAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockupTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.receiptTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([78, 192, 95, 173, 144, 239, 169, 27])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
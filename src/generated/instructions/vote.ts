@@ -27,7 +27,7 @@ export function vote(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.owner, isSigner: true, isWritable: true },
     { pubkey: accounts.proposal, isSigner: false, isWritable: true },
-    { pubkey: accounts.lockup, isSigner: false, isWritable: false },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
     { pubkey: accounts.voteRecord, isSigner: false, isWritable: true },
     { pubkey: accounts.ns, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
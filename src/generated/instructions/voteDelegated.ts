@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VoteDelegatedArgs {
+  args: types.VoteDelegatedArgsFields
+}
+
+export interface VoteDelegatedAccounts {
+  delegate: PublicKey
+  proposal: PublicKey
+  voteRecord: PublicKey
+  ns: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.VoteDelegatedArgs.layout("args")])
+
+export function voteDelegated(
+  args: VoteDelegatedArgs,
+  accounts: VoteDelegatedAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.delegate, isSigner: true, isWritable: true },
+    { pubkey: accounts.proposal, isSigner: false, isWritable: true },
+    { pubkey: accounts.voteRecord, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([109, 218, 148, 74, 172, 240, 0, 225])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.VoteDelegatedArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,52 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VoteWithReceiptArgs {
+  args: types.VoteWithReceiptArgsFields
+}
+
+export interface VoteWithReceiptAccounts {
+  holder: PublicKey
+  receiptTokenAccount: PublicKey
+  proposal: PublicKey
+  lockup: PublicKey
+  voteRecord: PublicKey
+  ns: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.VoteWithReceiptArgs.layout("args")])
+
+export function voteWithReceipt(
+  args: VoteWithReceiptArgs,
+  accounts: VoteWithReceiptAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.holder, isSigner: true, isWritable: true },
+    {
+      pubkey: accounts.receiptTokenAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.proposal, isSigner: false, isWritable: true },
+    { pubkey: accounts.lockup, isSigner: false, isWritable: true },
+    { pubkey: accounts.voteRecord, isSigner: false, isWritable: true },
+    { pubkey: accounts.ns, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([126, 42, 239, 12, 38, 92, 173, 152])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.VoteWithReceiptArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -7,28 +7,42 @@ export interface InitProposalArgsFields {
   startTs: BN
   endTs: BN
   uri: string
+  numChoices: number
+  choiceLabels: Array<string>
 }
 
 export interface InitProposalArgsJSON {
   startTs: string
   endTs: string
   uri: string
+  numChoices: number
+  choiceLabels: Array<string>
 }
 
 export class InitProposalArgs {
   readonly startTs: BN
   readonly endTs: BN
   readonly uri: string
+  readonly numChoices: number
+  readonly choiceLabels: Array<string>
 
   constructor(fields: InitProposalArgsFields) {
     this.startTs = fields.startTs
     this.endTs = fields.endTs
     this.uri = fields.uri
+    this.numChoices = fields.numChoices
+    this.choiceLabels = fields.choiceLabels
   }
 
   static layout(property?: string) {
     return borsh.struct(
-      [borsh.i64("startTs"), borsh.i64("endTs"), borsh.str("uri")],
+      [
+        borsh.i64("startTs"),
+        borsh.i64("endTs"),
+        borsh.str("uri"),
+        borsh.u8("numChoices"),
+        borsh.vec(borsh.str(), "choiceLabels"),
+      ],
       property
     )
   }
@@ -39,6 +53,8 @@ export class InitProposalArgs {
       startTs: obj.startTs,
       endTs: obj.endTs,
       uri: obj.uri,
+      numChoices: obj.numChoices,
+      choiceLabels: obj.choiceLabels,
     })
   }
 
@@ -47,6 +63,8 @@ export class InitProposalArgs {
       startTs: fields.startTs,
       endTs: fields.endTs,
       uri: fields.uri,
+      numChoices: fields.numChoices,
+      choiceLabels: fields.choiceLabels,
     }
   }
 
@@ -55,6 +73,8 @@ export class InitProposalArgs {
       startTs: this.startTs.toString(),
       endTs: this.endTs.toString(),
       uri: this.uri,
+      numChoices: this.numChoices,
+      choiceLabels: this.choiceLabels,
     }
   }
 
@@ -63,6 +83,8 @@ export class InitProposalArgs {
       startTs: new BN(obj.startTs),
       endTs: new BN(obj.endTs),
       uri: obj.uri,
+      numChoices: obj.numChoices,
+      choiceLabels: obj.choiceLabels,
     })
   }
 
@@ -0,0 +1,112 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface LockupCheckpointFields {
+  ts: BN
+  amount: BN
+  startTs: BN
+  endTs: BN
+  perpetual: boolean
+  targetVotingPct: number
+  vestingAmount: BN
+}
+
+export interface LockupCheckpointJSON {
+  ts: string
+  amount: string
+  startTs: string
+  endTs: string
+  perpetual: boolean
+  targetVotingPct: number
+  vestingAmount: string
+}
+
+export class LockupCheckpoint {
+  readonly ts: BN
+  readonly amount: BN
+  readonly startTs: BN
+  readonly endTs: BN
+  readonly perpetual: boolean
+  readonly targetVotingPct: number
+  readonly vestingAmount: BN
+
+  constructor(fields: LockupCheckpointFields) {
+    this.ts = fields.ts
+    this.amount = fields.amount
+    this.startTs = fields.startTs
+    this.endTs = fields.endTs
+    this.perpetual = fields.perpetual
+    this.targetVotingPct = fields.targetVotingPct
+    this.vestingAmount = fields.vestingAmount
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.i64("ts"),
+        borsh.u64("amount"),
+        borsh.i64("startTs"),
+        borsh.i64("endTs"),
+        borsh.bool("perpetual"),
+        borsh.u16("targetVotingPct"),
+        borsh.u64("vestingAmount"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new LockupCheckpoint({
+      ts: obj.ts,
+      amount: obj.amount,
+      startTs: obj.startTs,
+      endTs: obj.endTs,
+      perpetual: obj.perpetual,
+      targetVotingPct: obj.targetVotingPct,
+      vestingAmount: obj.vestingAmount,
+    })
+  }
+
+  static toEncodable(fields: LockupCheckpointFields) {
+    return {
+      ts: fields.ts,
+      amount: fields.amount,
+      startTs: fields.startTs,
+      endTs: fields.endTs,
+      perpetual: fields.perpetual,
+      targetVotingPct: fields.targetVotingPct,
+      vestingAmount: fields.vestingAmount,
+    }
+  }
+
+  toJSON(): LockupCheckpointJSON {
+    return {
+      ts: this.ts.toString(),
+      amount: this.amount.toString(),
+      startTs: this.startTs.toString(),
+      endTs: this.endTs.toString(),
+      perpetual: this.perpetual,
+      targetVotingPct: this.targetVotingPct,
+      vestingAmount: this.vestingAmount.toString(),
+    }
+  }
+
+  static fromJSON(obj: LockupCheckpointJSON): LockupCheckpoint {
+    return new LockupCheckpoint({
+      ts: new BN(obj.ts),
+      amount: new BN(obj.amount),
+      startTs: new BN(obj.startTs),
+      endTs: new BN(obj.endTs),
+      perpetual: obj.perpetual,
+      targetVotingPct: obj.targetVotingPct,
+      vestingAmount: new BN(obj.vestingAmount),
+    })
+  }
+
+  toEncodable() {
+    return LockupCheckpoint.toEncodable(this)
+  }
+}
@@ -0,0 +1,61 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RelockArgsFields {
+  amount: BN
+  endTs: BN
+}
+
+export interface RelockArgsJSON {
+  amount: string
+  endTs: string
+}
+
+export class RelockArgs {
+  readonly amount: BN
+  readonly endTs: BN
+
+  constructor(fields: RelockArgsFields) {
+    this.amount = fields.amount
+    this.endTs = fields.endTs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("amount"), borsh.i64("endTs")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RelockArgs({
+      amount: obj.amount,
+      endTs: obj.endTs,
+    })
+  }
+
+  static toEncodable(fields: RelockArgsFields) {
+    return {
+      amount: fields.amount,
+      endTs: fields.endTs,
+    }
+  }
+
+  toJSON(): RelockArgsJSON {
+    return {
+      amount: this.amount.toString(),
+      endTs: this.endTs.toString(),
+    }
+  }
+
+  static fromJSON(obj: RelockArgsJSON): RelockArgs {
+    return new RelockArgs({
+      amount: new BN(obj.amount),
+      endTs: new BN(obj.endTs),
+    })
+  }
+
+  toEncodable() {
+    return RelockArgs.toEncodable(this)
+  }
+}
@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SetEmergencyUnlockArgsFields {
+  emergencyUnlock: boolean
+}
+
+export interface SetEmergencyUnlockArgsJSON {
+  emergencyUnlock: boolean
+}
+
+export class SetEmergencyUnlockArgs {
+  readonly emergencyUnlock: boolean
+
+  constructor(fields: SetEmergencyUnlockArgsFields) {
+    this.emergencyUnlock = fields.emergencyUnlock
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.bool("emergencyUnlock")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SetEmergencyUnlockArgs({
+      emergencyUnlock: obj.emergencyUnlock,
+    })
+  }
+
+  static toEncodable(fields: SetEmergencyUnlockArgsFields) {
+    return {
+      emergencyUnlock: fields.emergencyUnlock,
+    }
+  }
+
+  toJSON(): SetEmergencyUnlockArgsJSON {
+    return {
+      emergencyUnlock: this.emergencyUnlock,
+    }
+  }
+
+  static fromJSON(obj: SetEmergencyUnlockArgsJSON): SetEmergencyUnlockArgs {
+    return new SetEmergencyUnlockArgs({
+      emergencyUnlock: obj.emergencyUnlock,
+    })
+  }
+
+  toEncodable() {
+    return SetEmergencyUnlockArgs.toEncodable(this)
+  }
+}
@@ -0,0 +1,67 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SetLockupMultipliersArgsFields {
+  targetVotingPct: number | null
+  targetRewardsPct: number | null
+}
+
+export interface SetLockupMultipliersArgsJSON {
+  targetVotingPct: number | null
+  targetRewardsPct: number | null
+}
+
+export class SetLockupMultipliersArgs {
+  readonly targetVotingPct: number | null
+  readonly targetRewardsPct: number | null
+
+  constructor(fields: SetLockupMultipliersArgsFields) {
+    this.targetVotingPct = fields.targetVotingPct
+    this.targetRewardsPct = fields.targetRewardsPct
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.option(borsh.u16(), "targetVotingPct"),
+        borsh.option(borsh.u16(), "targetRewardsPct"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SetLockupMultipliersArgs({
+      targetVotingPct: obj.targetVotingPct,
+      targetRewardsPct: obj.targetRewardsPct,
+    })
+  }
+
+  static toEncodable(fields: SetLockupMultipliersArgsFields) {
+    return {
+      targetVotingPct: fields.targetVotingPct,
+      targetRewardsPct: fields.targetRewardsPct,
+    }
+  }
+
+  toJSON(): SetLockupMultipliersArgsJSON {
+    return {
+      targetVotingPct: this.targetVotingPct,
+      targetRewardsPct: this.targetRewardsPct,
+    }
+  }
+
+  static fromJSON(obj: SetLockupMultipliersArgsJSON): SetLockupMultipliersArgs {
+    return new SetLockupMultipliersArgs({
+      targetVotingPct: obj.targetVotingPct,
+      targetRewardsPct: obj.targetRewardsPct,
+    })
+  }
+
+  toEncodable() {
+    return SetLockupMultipliersArgs.toEncodable(this)
+  }
+}
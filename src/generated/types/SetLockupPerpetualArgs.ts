@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SetLockupPerpetualArgsFields {
+  perpetual: boolean
+}
+
+export interface SetLockupPerpetualArgsJSON {
+  perpetual: boolean
+}
+
+export class SetLockupPerpetualArgs {
+  readonly perpetual: boolean
+
+  constructor(fields: SetLockupPerpetualArgsFields) {
+    this.perpetual = fields.perpetual
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.bool("perpetual")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SetLockupPerpetualArgs({
+      perpetual: obj.perpetual,
+    })
+  }
+
+  static toEncodable(fields: SetLockupPerpetualArgsFields) {
+    return {
+      perpetual: fields.perpetual,
+    }
+  }
+
+  toJSON(): SetLockupPerpetualArgsJSON {
+    return {
+      perpetual: this.perpetual,
+    }
+  }
+
+  static fromJSON(obj: SetLockupPerpetualArgsJSON): SetLockupPerpetualArgs {
+    return new SetLockupPerpetualArgs({
+      perpetual: obj.perpetual,
+    })
+  }
+
+  toEncodable() {
+    return SetLockupPerpetualArgs.toEncodable(this)
+  }
+}
@@ -0,0 +1,61 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SplitLockupArgsFields {
+  amount: BN
+  newIndex: number
+}
+
+export interface SplitLockupArgsJSON {
+  amount: string
+  newIndex: number
+}
+
+export class SplitLockupArgs {
+  readonly amount: BN
+  readonly newIndex: number
+
+  constructor(fields: SplitLockupArgsFields) {
+    this.amount = fields.amount
+    this.newIndex = fields.newIndex
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("amount"), borsh.u32("newIndex")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SplitLockupArgs({
+      amount: obj.amount,
+      newIndex: obj.newIndex,
+    })
+  }
+
+  static toEncodable(fields: SplitLockupArgsFields) {
+    return {
+      amount: fields.amount,
+      newIndex: fields.newIndex,
+    }
+  }
+
+  toJSON(): SplitLockupArgsJSON {
+    return {
+      amount: this.amount.toString(),
+      newIndex: this.newIndex,
+    }
+  }
+
+  static fromJSON(obj: SplitLockupArgsJSON): SplitLockupArgs {
+    return new SplitLockupArgs({
+      amount: new BN(obj.amount),
+      newIndex: obj.newIndex,
+    })
+  }
+
+  toEncodable() {
+    return SplitLockupArgs.toEncodable(this)
+  }
+}
@@ -6,24 +6,31 @@ import * as borsh from "@coral-xyz/borsh"
 export interface StakeArgsFields {
   amount: BN
   endTs: BN
+  index: number
 }
 
 export interface StakeArgsJSON {
   amount: string
   endTs: string
+  index: number
 }
 
 export class StakeArgs {
   readonly amount: BN
   readonly endTs: BN
+  readonly index: number
 
   constructor(fields: StakeArgsFields) {
     this.amount = fields.amount
     this.endTs = fields.endTs
+    this.index = fields.index
   }
 
   static layout(property?: string) {
-    return borsh.struct([borsh.u64("amount"), borsh.i64("endTs")], property)
+    return borsh.struct(
+      [borsh.u64("amount"), borsh.i64("endTs"), borsh.u32("index")],
+      property
+    )
   }
 
   // eslint-disable-next-line @typescript-eslint/no-explicit-any
@@ -31,6 +38,7 @@ export class StakeArgs {
     return new StakeArgs({
       amount: obj.amount,
       endTs: obj.endTs,
+      index: obj.index,
     })
   }
 
@@ -38,6 +46,7 @@ export class StakeArgs {
     return {
       amount: fields.amount,
       endTs: fields.endTs,
+      index: fields.index,
     }
   }
 
@@ -45,6 +54,7 @@ export class StakeArgs {
     return {
       amount: this.amount.toString(),
       endTs: this.endTs.toString(),
+      index: this.index,
     }
   }
 
@@ -52,6 +62,7 @@ export class StakeArgs {
     return new StakeArgs({
       amount: new BN(obj.amount),
       endTs: new BN(obj.endTs),
+      index: obj.index,
     })
   }
 
@@ -0,0 +1,103 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface StakeToVestingArgsFields {
+  index: number
+  amount: BN
+  endTs: BN
+  vestingCliffTs: BN
+  vestingEndTs: BN
+  disableRewards: boolean
+}
+
+export interface StakeToVestingArgsJSON {
+  index: number
+  amount: string
+  endTs: string
+  vestingCliffTs: string
+  vestingEndTs: string
+  disableRewards: boolean
+}
+
+export class StakeToVestingArgs {
+  readonly index: number
+  readonly amount: BN
+  readonly endTs: BN
+  readonly vestingCliffTs: BN
+  readonly vestingEndTs: BN
+  readonly disableRewards: boolean
+
+  constructor(fields: StakeToVestingArgsFields) {
+    this.index = fields.index
+    this.amount = fields.amount
+    this.endTs = fields.endTs
+    this.vestingCliffTs = fields.vestingCliffTs
+    this.vestingEndTs = fields.vestingEndTs
+    this.disableRewards = fields.disableRewards
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u32("index"),
+        borsh.u64("amount"),
+        borsh.i64("endTs"),
+        borsh.i64("vestingCliffTs"),
+        borsh.i64("vestingEndTs"),
+        borsh.bool("disableRewards"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new StakeToVestingArgs({
+      index: obj.index,
+      amount: obj.amount,
+      endTs: obj.endTs,
+      vestingCliffTs: obj.vestingCliffTs,
+      vestingEndTs: obj.vestingEndTs,
+      disableRewards: obj.disableRewards,
+    })
+  }
+
+  static toEncodable(fields: StakeToVestingArgsFields) {
+    return {
+      index: fields.index,
+      amount: fields.amount,
+      endTs: fields.endTs,
+      vestingCliffTs: fields.vestingCliffTs,
+      vestingEndTs: fields.vestingEndTs,
+      disableRewards: fields.disableRewards,
+    }
+  }
+
+  toJSON(): StakeToVestingArgsJSON {
+    return {
+      index: this.index,
+      amount: this.amount.toString(),
+      endTs: this.endTs.toString(),
+      vestingCliffTs: this.vestingCliffTs.toString(),
+      vestingEndTs: this.vestingEndTs.toString(),
+      disableRewards: this.disableRewards,
+    }
+  }
+
+  static fromJSON(obj: StakeToVestingArgsJSON): StakeToVestingArgs {
+    return new StakeToVestingArgs({
+      index: obj.index,
+      amount: new BN(obj.amount),
+      endTs: new BN(obj.endTs),
+      vestingCliffTs: new BN(obj.vestingCliffTs),
+      vestingEndTs: new BN(obj.vestingEndTs),
+      disableRewards: obj.disableRewards,
+    })
+  }
+
+  toEncodable() {
+    return StakeToVestingArgs.toEncodable(this)
+  }
+}
@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface TransferLockupArgsFields {
+  newIndex: number
+}
+
+export interface TransferLockupArgsJSON {
+  newIndex: number
+}
+
+export class TransferLockupArgs {
+  readonly newIndex: number
+
+  constructor(fields: TransferLockupArgsFields) {
+    this.newIndex = fields.newIndex
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u32("newIndex")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new TransferLockupArgs({
+      newIndex: obj.newIndex,
+    })
+  }
+
+  static toEncodable(fields: TransferLockupArgsFields) {
+    return {
+      newIndex: fields.newIndex,
+    }
+  }
+
+  toJSON(): TransferLockupArgsJSON {
+    return {
+      newIndex: this.newIndex,
+    }
+  }
+
+  static fromJSON(obj: TransferLockupArgsJSON): TransferLockupArgs {
+    return new TransferLockupArgs({
+      newIndex: obj.newIndex,
+    })
+  }
+
+  toEncodable() {
+    return TransferLockupArgs.toEncodable(this)
+  }
+}
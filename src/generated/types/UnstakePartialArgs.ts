@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UnstakePartialArgsFields {
+  amount: BN
+}
+
+export interface UnstakePartialArgsJSON {
+  amount: string
+}
+
+export class UnstakePartialArgs {
+  readonly amount: BN
+
+  constructor(fields: UnstakePartialArgsFields) {
+    this.amount = fields.amount
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("amount")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UnstakePartialArgs({
+      amount: obj.amount,
+    })
+  }
+
+  static toEncodable(fields: UnstakePartialArgsFields) {
+    return {
+      amount: fields.amount,
+    }
+  }
+
+  toJSON(): UnstakePartialArgsJSON {
+    return {
+      amount: this.amount.toString(),
+    }
+  }
+
+  static fromJSON(obj: UnstakePartialArgsJSON): UnstakePartialArgs {
+    return new UnstakePartialArgs({
+      amount: new BN(obj.amount),
+    })
+  }
+
+  toEncodable() {
+    return UnstakePartialArgs.toEncodable(this)
+  }
+}
@@ -14,6 +14,10 @@ export interface UpdateNamespaceArgsFields {
   proposalMinVotingPowerForQuorum: BN
   proposalMinPassPct: number
   proposalCanUpdateAfterVotes: boolean
+  lockupEarlyUnlockPenaltyPct: number
+  lockupVotingPowerMode: number
+  lockupUnstakeCooldown: BN
+  lockupVotingCurve: Array<types.VotingCurvePointFields>
 }
 
 export interface UpdateNamespaceArgsJSON {
@@ -27,6 +31,10 @@ export interface UpdateNamespaceArgsJSON {
   proposalMinVotingPowerForQuorum: string
   proposalMinPassPct: number
   proposalCanUpdateAfterVotes: boolean
+  lockupEarlyUnlockPenaltyPct: number
+  lockupVotingPowerMode: number
+  lockupUnstakeCooldown: string
+  lockupVotingCurve: Array<types.VotingCurvePointJSON>
 }
 
 export class UpdateNamespaceArgs {
@@ -40,6 +48,10 @@ export class UpdateNamespaceArgs {
   readonly proposalMinVotingPowerForQuorum: BN
   readonly proposalMinPassPct: number
   readonly proposalCanUpdateAfterVotes: boolean
+  readonly lockupEarlyUnlockPenaltyPct: number
+  readonly lockupVotingPowerMode: number
+  readonly lockupUnstakeCooldown: BN
+  readonly lockupVotingCurve: Array<types.VotingCurvePoint>
 
   constructor(fields: UpdateNamespaceArgsFields) {
     this.securityCouncil = fields.securityCouncil
@@ -53,6 +65,12 @@ export class UpdateNamespaceArgs {
       fields.proposalMinVotingPowerForQuorum
     this.proposalMinPassPct = fields.proposalMinPassPct
     this.proposalCanUpdateAfterVotes = fields.proposalCanUpdateAfterVotes
+    this.lockupEarlyUnlockPenaltyPct = fields.lockupEarlyUnlockPenaltyPct
+    this.lockupVotingPowerMode = fields.lockupVotingPowerMode
+    this.lockupUnstakeCooldown = fields.lockupUnstakeCooldown
+    this.lockupVotingCurve = fields.lockupVotingCurve.map(
+      (item) => new types.VotingCurvePoint({ ...item })
+    )
   }
 
   static layout(property?: string) {
@@ -68,6 +86,10 @@ export class UpdateNamespaceArgs {
         borsh.u64("proposalMinVotingPowerForQuorum"),
         borsh.u16("proposalMinPassPct"),
         borsh.bool("proposalCanUpdateAfterVotes"),
+        borsh.u16("lockupEarlyUnlockPenaltyPct"),
+        borsh.u8("lockupVotingPowerMode"),
+        borsh.i64("lockupUnstakeCooldown"),
+        borsh.vec(types.VotingCurvePoint.layout(), "lockupVotingCurve"),
       ],
       property
     )
@@ -86,6 +108,14 @@ export class UpdateNamespaceArgs {
       proposalMinVotingPowerForQuorum: obj.proposalMinVotingPowerForQuorum,
       proposalMinPassPct: obj.proposalMinPassPct,
       proposalCanUpdateAfterVotes: obj.proposalCanUpdateAfterVotes,
+      lockupEarlyUnlockPenaltyPct: obj.lockupEarlyUnlockPenaltyPct,
+      lockupVotingPowerMode: obj.lockupVotingPowerMode,
+      lockupUnstakeCooldown: obj.lockupUnstakeCooldown,
+      lockupVotingCurve: obj.lockupVotingCurve.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.VotingCurvePoint.fromDecoded(item)
+      ),
     })
   }
 
@@ -101,6 +131,12 @@ export class UpdateNamespaceArgs {
       proposalMinVotingPowerForQuorum: fields.proposalMinVotingPowerForQuorum,
       proposalMinPassPct: fields.proposalMinPassPct,
       proposalCanUpdateAfterVotes: fields.proposalCanUpdateAfterVotes,
+      lockupEarlyUnlockPenaltyPct: fields.lockupEarlyUnlockPenaltyPct,
+      lockupVotingPowerMode: fields.lockupVotingPowerMode,
+      lockupUnstakeCooldown: fields.lockupUnstakeCooldown,
+      lockupVotingCurve: fields.lockupVotingCurve.map((item) =>
+        types.VotingCurvePoint.toEncodable(item)
+      ),
     }
   }
 
@@ -117,6 +153,10 @@ export class UpdateNamespaceArgs {
         this.proposalMinVotingPowerForQuorum.toString(),
       proposalMinPassPct: this.proposalMinPassPct,
       proposalCanUpdateAfterVotes: this.proposalCanUpdateAfterVotes,
+      lockupEarlyUnlockPenaltyPct: this.lockupEarlyUnlockPenaltyPct,
+      lockupVotingPowerMode: this.lockupVotingPowerMode,
+      lockupUnstakeCooldown: this.lockupUnstakeCooldown.toString(),
+      lockupVotingCurve: this.lockupVotingCurve.map((item) => item.toJSON()),
     }
   }
 
@@ -134,6 +174,12 @@ export class UpdateNamespaceArgs {
       ),
       proposalMinPassPct: obj.proposalMinPassPct,
       proposalCanUpdateAfterVotes: obj.proposalCanUpdateAfterVotes,
+      lockupEarlyUnlockPenaltyPct: obj.lockupEarlyUnlockPenaltyPct,
+      lockupVotingPowerMode: obj.lockupVotingPowerMode,
+      lockupUnstakeCooldown: new BN(obj.lockupUnstakeCooldown),
+      lockupVotingCurve: obj.lockupVotingCurve.map((item) =>
+        types.VotingCurvePoint.fromJSON(item)
+      ),
     })
   }
 
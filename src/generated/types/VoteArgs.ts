@@ -5,45 +5,64 @@ import * as borsh from "@coral-xyz/borsh"
 
 export interface VoteArgsFields {
   choice: number
+  choiceWeightsPct: Uint8Array
 }
 
 export interface VoteArgsJSON {
   choice: number
+  choiceWeightsPct: Array<number>
 }
 
 export class VoteArgs {
   readonly choice: number
+  readonly choiceWeightsPct: Uint8Array
 
   constructor(fields: VoteArgsFields) {
     this.choice = fields.choice
+    this.choiceWeightsPct = fields.choiceWeightsPct
   }
 
   static layout(property?: string) {
-    return borsh.struct([borsh.u8("choice")], property)
+    return borsh.struct(
+      [borsh.u8("choice"), borsh.vecU8("choiceWeightsPct")],
+      property
+    )
   }
 
   // eslint-disable-next-line @typescript-eslint/no-explicit-any
   static fromDecoded(obj: any) {
     return new VoteArgs({
       choice: obj.choice,
+      choiceWeightsPct: new Uint8Array(
+        obj.choiceWeightsPct.buffer,
+        obj.choiceWeightsPct.byteOffset,
+        obj.choiceWeightsPct.length
+      ),
     })
   }
 
   static toEncodable(fields: VoteArgsFields) {
     return {
       choice: fields.choice,
+      choiceWeightsPct: Buffer.from(
+        fields.choiceWeightsPct.buffer,
+        fields.choiceWeightsPct.byteOffset,
+        fields.choiceWeightsPct.length
+      ),
     }
   }
 
   toJSON(): VoteArgsJSON {
     return {
       choice: this.choice,
+      choiceWeightsPct: Array.from(this.choiceWeightsPct.values()),
     }
   }
 
   static fromJSON(obj: VoteArgsJSON): VoteArgs {
     return new VoteArgs({
       choice: obj.choice,
+      choiceWeightsPct: Uint8Array.from(obj.choiceWeightsPct),
     })
   }
 
@@ -0,0 +1,72 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VoteDelegatedArgsFields {
+  choice: number
+  choiceWeightsPct: Uint8Array
+}
+
+export interface VoteDelegatedArgsJSON {
+  choice: number
+  choiceWeightsPct: Array<number>
+}
+
+export class VoteDelegatedArgs {
+  readonly choice: number
+  readonly choiceWeightsPct: Uint8Array
+
+  constructor(fields: VoteDelegatedArgsFields) {
+    this.choice = fields.choice
+    this.choiceWeightsPct = fields.choiceWeightsPct
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.u8("choice"), borsh.vecU8("choiceWeightsPct")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VoteDelegatedArgs({
+      choice: obj.choice,
+      choiceWeightsPct: new Uint8Array(
+        obj.choiceWeightsPct.buffer,
+        obj.choiceWeightsPct.byteOffset,
+        obj.choiceWeightsPct.length
+      ),
+    })
+  }
+
+  static toEncodable(fields: VoteDelegatedArgsFields) {
+    return {
+      choice: fields.choice,
+      choiceWeightsPct: Buffer.from(
+        fields.choiceWeightsPct.buffer,
+        fields.choiceWeightsPct.byteOffset,
+        fields.choiceWeightsPct.length
+      ),
+    }
+  }
+
+  toJSON(): VoteDelegatedArgsJSON {
+    return {
+      choice: this.choice,
+      choiceWeightsPct: Array.from(this.choiceWeightsPct.values()),
+    }
+  }
+
+  static fromJSON(obj: VoteDelegatedArgsJSON): VoteDelegatedArgs {
+    return new VoteDelegatedArgs({
+      choice: obj.choice,
+      choiceWeightsPct: Uint8Array.from(obj.choiceWeightsPct),
+    })
+  }
+
+  toEncodable() {
+    return VoteDelegatedArgs.toEncodable(this)
+  }
+}
@@ -0,0 +1,72 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VoteWithReceiptArgsFields {
+  choice: number
+  choiceWeightsPct: Uint8Array
+}
+
+export interface VoteWithReceiptArgsJSON {
+  choice: number
+  choiceWeightsPct: Array<number>
+}
+
+export class VoteWithReceiptArgs {
+  readonly choice: number
+  readonly choiceWeightsPct: Uint8Array
+
+  constructor(fields: VoteWithReceiptArgsFields) {
+    this.choice = fields.choice
+    this.choiceWeightsPct = fields.choiceWeightsPct
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.u8("choice"), borsh.vecU8("choiceWeightsPct")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VoteWithReceiptArgs({
+      choice: obj.choice,
+      choiceWeightsPct: new Uint8Array(
+        obj.choiceWeightsPct.buffer,
+        obj.choiceWeightsPct.byteOffset,
+        obj.choiceWeightsPct.length
+      ),
+    })
+  }
+
+  static toEncodable(fields: VoteWithReceiptArgsFields) {
+    return {
+      choice: fields.choice,
+      choiceWeightsPct: Buffer.from(
+        fields.choiceWeightsPct.buffer,
+        fields.choiceWeightsPct.byteOffset,
+        fields.choiceWeightsPct.length
+      ),
+    }
+  }
+
+  toJSON(): VoteWithReceiptArgsJSON {
+    return {
+      choice: this.choice,
+      choiceWeightsPct: Array.from(this.choiceWeightsPct.values()),
+    }
+  }
+
+  static fromJSON(obj: VoteWithReceiptArgsJSON): VoteWithReceiptArgs {
+    return new VoteWithReceiptArgs({
+      choice: obj.choice,
+      choiceWeightsPct: Uint8Array.from(obj.choiceWeightsPct),
+    })
+  }
+
+  toEncodable() {
+    return VoteWithReceiptArgs.toEncodable(this)
+  }
+}
@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VotingCurvePointFields {
+  duration: BN
+  multiplierPct: number
+}
+
+export interface VotingCurvePointJSON {
+  duration: string
+  multiplierPct: number
+}
+
+export class VotingCurvePoint {
+  readonly duration: BN
+  readonly multiplierPct: number
+
+  constructor(fields: VotingCurvePointFields) {
+    this.duration = fields.duration
+    this.multiplierPct = fields.multiplierPct
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.i64("duration"), borsh.u16("multiplierPct")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VotingCurvePoint({
+      duration: obj.duration,
+      multiplierPct: obj.multiplierPct,
+    })
+  }
+
+  static toEncodable(fields: VotingCurvePointFields) {
+    return {
+      duration: fields.duration,
+      multiplierPct: fields.multiplierPct,
+    }
+  }
+
+  toJSON(): VotingCurvePointJSON {
+    return {
+      duration: this.duration.toString(),
+      multiplierPct: this.multiplierPct,
+    }
+  }
+
+  static fromJSON(obj: VotingCurvePointJSON): VotingCurvePoint {
+    return new VotingCurvePoint({
+      duration: new BN(obj.duration),
+      multiplierPct: obj.multiplierPct,
+    })
+  }
+
+  toEncodable() {
+    return VotingCurvePoint.toEncodable(this)
+  }
+}
@@ -1,3 +1,5 @@
+export { ChangeVoteArgs } from "./ChangeVoteArgs"
+export type { ChangeVoteArgsFields, ChangeVoteArgsJSON } from "./ChangeVoteArgs"
 export { ClaimFromDistributionArgs } from "./ClaimFromDistributionArgs"
 export type {
   ClaimFromDistributionArgsFields,
@@ -13,10 +15,49 @@ export type {
   InitProposalArgsFields,
   InitProposalArgsJSON,
 } from "./InitProposalArgs"
+export { RelockArgs } from "./RelockArgs"
+export type { RelockArgsFields, RelockArgsJSON } from "./RelockArgs"
+export { SetEmergencyUnlockArgs } from "./SetEmergencyUnlockArgs"
+export type {
+  SetEmergencyUnlockArgsFields,
+  SetEmergencyUnlockArgsJSON,
+} from "./SetEmergencyUnlockArgs"
+export { SetLockupMultipliersArgs } from "./SetLockupMultipliersArgs"
+export type {
+  SetLockupMultipliersArgsFields,
+  SetLockupMultipliersArgsJSON,
+} from "./SetLockupMultipliersArgs"
+export { SetLockupPerpetualArgs } from "./SetLockupPerpetualArgs"
+export type {
+  SetLockupPerpetualArgsFields,
+  SetLockupPerpetualArgsJSON,
+} from "./SetLockupPerpetualArgs"
+export { SplitLockupArgs } from "./SplitLockupArgs"
+export type {
+  SplitLockupArgsFields,
+  SplitLockupArgsJSON,
+} from "./SplitLockupArgs"
+export { StakeForArgs } from "./StakeForArgs"
+export type { StakeForArgsFields, StakeForArgsJSON } from "./StakeForArgs"
+export { StakeToVestingArgs } from "./StakeToVestingArgs"
+export type {
+  StakeToVestingArgsFields,
+  StakeToVestingArgsJSON,
+} from "./StakeToVestingArgs"
 export { StakeToArgs } from "./StakeToArgs"
 export type { StakeToArgsFields, StakeToArgsJSON } from "./StakeToArgs"
 export { StakeArgs } from "./StakeArgs"
 export type { StakeArgsFields, StakeArgsJSON } from "./StakeArgs"
+export { TransferLockupArgs } from "./TransferLockupArgs"
+export type {
+  TransferLockupArgsFields,
+  TransferLockupArgsJSON,
+} from "./TransferLockupArgs"
+export { UnstakePartialArgs } from "./UnstakePartialArgs"
+export type {
+  UnstakePartialArgsFields,
+  UnstakePartialArgsJSON,
+} from "./UnstakePartialArgs"
 export { UpdateDistributionArgs } from "./UpdateDistributionArgs"
 export type {
   UpdateDistributionArgsFields,
@@ -32,5 +73,25 @@ export type {
   UpdateProposalArgsFields,
   UpdateProposalArgsJSON,
 } from "./UpdateProposalArgs"
+export { VoteDelegatedArgs } from "./VoteDelegatedArgs"
+export type {
+  VoteDelegatedArgsFields,
+  VoteDelegatedArgsJSON,
+} from "./VoteDelegatedArgs"
+export { VoteWithReceiptArgs } from "./VoteWithReceiptArgs"
+export type {
+  VoteWithReceiptArgsFields,
+  VoteWithReceiptArgsJSON,
+} from "./VoteWithReceiptArgs"
 export { VoteArgs } from "./VoteArgs"
 export type { VoteArgsFields, VoteArgsJSON } from "./VoteArgs"
+export { VotingCurvePoint } from "./VotingCurvePoint"
+export type {
+  VotingCurvePointFields,
+  VotingCurvePointJSON,
+} from "./VotingCurvePoint"
+export { LockupCheckpoint } from "./LockupCheckpoint"
+export type {
+  LockupCheckpointFields,
+  LockupCheckpointJSON,
+} from "./LockupCheckpoint"
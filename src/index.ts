@@ -12,15 +12,30 @@ import {
   unstake,
   updateNamespace,
   updateProposal,
+  finalizeProposal,
+  cancelProposal,
+  vetoProposal,
   vote,
+  changeVote,
+  revokeVote,
   stakeTo,
+  stakeFor,
+  mintLockupReceipt,
+  refreshLockupReceipt,
+  voteWithReceipt,
+  requestUnstakeWithReceipt,
+  unstakeWithReceipt,
   initDistribution,
   claimFromDistribution,
   updateDistribution,
   withdrawFromDistribution,
 } from "./generated/instructions";
+import { VotingCurvePointFields } from "./generated/types";
 import BN from "bn.js";
-import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import { createHash } from "crypto";
 
 export * from "./generated/instructions";
@@ -75,9 +90,69 @@ export class VeTokenSDK {
     return pda;
   }
 
-  pdaLockup(owner: PublicKey) {
+  // index 0 is the original lockup seeded by [b"lockup", ns, owner], an empty
+  // seed derives the same address
+  pdaLockup(owner: PublicKey, index = 0) {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("lockup"),
+        this.pdaNamespace().toBuffer(),
+        owner.toBuffer(),
+        index === 0
+          ? Buffer.alloc(0)
+          : new BN(index).toArrayLike(Buffer, "le", 4),
+      ],
+      PROGRAM_ID
+    );
+    return pda;
+  }
+
+  pdaDelegation(lockup: PublicKey) {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("delegation"),
+        this.pdaNamespace().toBuffer(),
+        lockup.toBuffer(),
+      ],
+      PROGRAM_ID
+    );
+    return pda;
+  }
+
+  pdaReceiptMint(lockup: PublicKey) {
     const [pda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("lockup"), this.pdaNamespace().toBuffer(), owner.toBuffer()],
+      [Buffer.from("lockup_receipt"), lockup.toBuffer()],
+      PROGRAM_ID
+    );
+    return pda;
+  }
+
+  pdaReceiptAuthority() {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("receipt_authority"), this.pdaNamespace().toBuffer()],
+      PROGRAM_ID
+    );
+    return pda;
+  }
+
+  // the receipts are Token-2022 NFTs, unlike the staked token
+  receiptAta(holder: PublicKey, lockup: PublicKey) {
+    return getAssociatedTokenAddressSync(
+      this.pdaReceiptMint(lockup),
+      holder,
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+  }
+
+  pdaReceiptVoteRecord(lockup: PublicKey, proposal: PublicKey) {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("receipt_vote_record"),
+        this.pdaNamespace().toBuffer(),
+        lockup.toBuffer(),
+        proposal.toBuffer(),
+      ],
       PROGRAM_ID
     );
     return pda;
@@ -168,7 +243,11 @@ export class VeTokenSDK {
     lockupMaxSaturation: BN,
     proposalMinVotingPowerForQuorum: BN,
     proposalMinPassPct: number,
-    proposalCanUpdateAfterVotes: boolean
+    proposalCanUpdateAfterVotes: boolean,
+    lockupEarlyUnlockPenaltyPct: number,
+    lockupVotingPowerMode: number,
+    lockupUnstakeCooldown: BN,
+    lockupVotingCurve: VotingCurvePointFields[]
   ) {
     const ix = updateNamespace(
       {
@@ -183,6 +262,10 @@ export class VeTokenSDK {
           proposalMinVotingPowerForQuorum,
           proposalMinPassPct,
           proposalCanUpdateAfterVotes,
+          lockupEarlyUnlockPenaltyPct,
+          lockupVotingPowerMode,
+          lockupUnstakeCooldown,
+          lockupVotingCurve,
         },
       },
       {
@@ -193,11 +276,11 @@ export class VeTokenSDK {
     return this.newTx().add(ix);
   }
 
-  txStake(owner: PublicKey, amount: BN, endTs: BN) {
-    const lockup = this.pdaLockup(owner);
+  txStake(owner: PublicKey, amount: BN, endTs: BN, index = 0) {
+    const lockup = this.pdaLockup(owner, index);
     const ix = stake(
       {
-        args: { amount, endTs },
+        args: { amount, endTs, index },
       },
       {
         owner,
@@ -236,14 +319,40 @@ export class VeTokenSDK {
     return this.newTx().add(ix);
   }
 
-  txUnstake(owner: PublicKey) {
-    const lockup = this.pdaLockup(owner);
+  // anyone can stake for the beneficiary, endTs is only used for a new lockup
+  txStakeFor(payer: PublicKey, beneficiary: PublicKey, amount: BN, endTs: BN) {
+    const lockup = this.pdaLockup(beneficiary);
+    const ix = stakeFor(
+      {
+        args: { amount, endTs },
+      },
+      {
+        payer,
+        beneficiary,
+        tokenMint: this.tokenMint,
+        tokenAccount: this.ata(payer),
+        lockup,
+        lockupTokenAccount: this.ata(lockup),
+        ns: this.pdaNamespace(),
+        tokenProgram: this.tokenProgram,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      }
+    );
+    return this.newTx().add(ix);
+  }
+
+  // delegated lockups can only be unstaked in the emergency unlock mode, which
+  // closes the delegation account, otherwise the program id stands for no account
+  txUnstake(owner: PublicKey, index = 0, delegated = false) {
+    const lockup = this.pdaLockup(owner, index);
     const ix = unstake({
       owner,
       tokenMint: this.tokenMint,
       tokenAccount: this.ata(owner),
       lockup,
       lockupTokenAccount: this.ata(lockup),
+      delegation: delegated ? this.pdaDelegation(lockup) : PROGRAM_ID,
       ns: this.pdaNamespace(),
       tokenProgram: this.tokenProgram,
       systemProgram: SystemProgram.programId,
@@ -257,7 +366,9 @@ export class VeTokenSDK {
     proposal_nonce: number,
     uri: string,
     startTs: BN,
-    endTs: BN
+    endTs: BN,
+    numChoices: number,
+    choiceLabels: string[] = []
   ) {
     const ix = initProposal(
       {
@@ -265,6 +376,8 @@ export class VeTokenSDK {
           startTs,
           endTs,
           uri,
+          numChoices,
+          choiceLabels,
         },
       },
       {
@@ -301,20 +414,199 @@ export class VeTokenSDK {
     return this.newTx().add(ix);
   }
 
-  txVote(owner: PublicKey, proposal: PublicKey, choice: number) {
+  // anyone can finalize a proposal once it has ended
+  txFinalizeProposal(proposal: PublicKey) {
+    const ix = finalizeProposal({
+      proposal,
+      ns: this.pdaNamespace(),
+    });
+    return this.newTx().add(ix);
+  }
+
+  txCancelProposal(reviewCouncil: PublicKey, proposal: PublicKey) {
+    const ix = cancelProposal({
+      reviewCouncil,
+      proposal,
+      ns: this.pdaNamespace(),
+    });
+    return this.newTx().add(ix);
+  }
+
+  txVetoProposal(proposal: PublicKey) {
+    const ix = vetoProposal({
+      securityCouncil: this.securityCouncil,
+      proposal,
+      ns: this.pdaNamespace(),
+    });
+    return this.newTx().add(ix);
+  }
+
+  // otherLockupIndexes are the owner's other lockups whose voting power is
+  // summed up with the lockup at index, choiceWeightsPct optionally splits the
+  // voting power over the choices, must sum up to 100 and have its largest weight at choice
+  txVote(
+    owner: PublicKey,
+    proposal: PublicKey,
+    choice: number,
+    index = 0,
+    otherLockupIndexes: number[] = [],
+    choiceWeightsPct: number[] = []
+  ) {
     const ix = vote(
       {
-        args: { choice },
+        args: {
+          choice,
+          choiceWeightsPct: Uint8Array.from(choiceWeightsPct),
+        },
       },
       {
         ns: this.pdaNamespace(),
         owner,
         proposal,
-        lockup: this.pdaLockup(owner),
+        lockup: this.pdaLockup(owner, index),
         voteRecord: this.pdaVoteRecord(owner, proposal),
         systemProgram: SystemProgram.programId,
       }
     );
+    ix.keys.push(
+      ...otherLockupIndexes.map((i) => ({
+        pubkey: this.pdaLockup(owner, i),
+        isSigner: false,
+        isWritable: true,
+      }))
+    );
+    return this.newTx().add(ix);
+  }
+
+  // the vote is moved to choice with the current voting power of the lockups
+  txChangeVote(
+    owner: PublicKey,
+    proposal: PublicKey,
+    choice: number,
+    index = 0,
+    otherLockupIndexes: number[] = [],
+    choiceWeightsPct: number[] = []
+  ) {
+    const ix = changeVote(
+      {
+        args: {
+          choice,
+          choiceWeightsPct: Uint8Array.from(choiceWeightsPct),
+        },
+      },
+      {
+        owner,
+        proposal,
+        lockup: this.pdaLockup(owner, index),
+        voteRecord: this.pdaVoteRecord(owner, proposal),
+        ns: this.pdaNamespace(),
+      }
+    );
+    ix.keys.push(
+      ...otherLockupIndexes.map((i) => ({
+        pubkey: this.pdaLockup(owner, i),
+        isSigner: false,
+        isWritable: true,
+      }))
+    );
+    return this.newTx().add(ix);
+  }
+
+  txRevokeVote(owner: PublicKey, proposal: PublicKey) {
+    const ix = revokeVote({
+      owner,
+      proposal,
+      voteRecord: this.pdaVoteRecord(owner, proposal),
+      ns: this.pdaNamespace(),
+    });
+    return this.newTx().add(ix);
+  }
+
+  txMintLockupReceipt(owner: PublicKey, index = 0) {
+    const lockup = this.pdaLockup(owner, index);
+    const ix = mintLockupReceipt({
+      owner,
+      lockup,
+      receiptMint: this.pdaReceiptMint(lockup),
+      receiptAuthority: this.pdaReceiptAuthority(),
+      receiptTokenAccount: this.receiptAta(owner, lockup),
+      ns: this.pdaNamespace(),
+      receiptTokenProgram: TOKEN_2022_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    });
+    return this.newTx().add(ix);
+  }
+
+  txRefreshLockupReceipt(payer: PublicKey, lockup: PublicKey) {
+    const ix = refreshLockupReceipt({
+      payer,
+      lockup,
+      receiptMint: this.pdaReceiptMint(lockup),
+      receiptAuthority: this.pdaReceiptAuthority(),
+      ns: this.pdaNamespace(),
+      receiptTokenProgram: TOKEN_2022_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    });
+    return this.newTx().add(ix);
+  }
+
+  // the holder of the receipt acts for the lockup, which is still seeded by its original owner
+  txVoteWithReceipt(
+    holder: PublicKey,
+    lockup: PublicKey,
+    proposal: PublicKey,
+    choice: number,
+    choiceWeightsPct: number[] = []
+  ) {
+    const ix = voteWithReceipt(
+      {
+        args: {
+          choice,
+          choiceWeightsPct: Uint8Array.from(choiceWeightsPct),
+        },
+      },
+      {
+        holder,
+        receiptTokenAccount: this.receiptAta(holder, lockup),
+        proposal,
+        lockup,
+        voteRecord: this.pdaReceiptVoteRecord(lockup, proposal),
+        ns: this.pdaNamespace(),
+        systemProgram: SystemProgram.programId,
+      }
+    );
+    return this.newTx().add(ix);
+  }
+
+  txRequestUnstakeWithReceipt(holder: PublicKey, lockup: PublicKey) {
+    const ix = requestUnstakeWithReceipt({
+      holder,
+      receiptTokenAccount: this.receiptAta(holder, lockup),
+      lockup,
+      ns: this.pdaNamespace(),
+    });
+    return this.newTx().add(ix);
+  }
+
+  txUnstakeWithReceipt(holder: PublicKey, owner: PublicKey, index = 0) {
+    const lockup = this.pdaLockup(owner, index);
+    const ix = unstakeWithReceipt({
+      holder,
+      owner,
+      tokenMint: this.tokenMint,
+      tokenAccount: this.ata(holder),
+      receiptMint: this.pdaReceiptMint(lockup),
+      receiptTokenAccount: this.receiptAta(holder, lockup),
+      receiptAuthority: this.pdaReceiptAuthority(),
+      lockup,
+      lockupTokenAccount: this.ata(lockup),
+      ns: this.pdaNamespace(),
+      tokenProgram: this.tokenProgram,
+      receiptTokenProgram: TOKEN_2022_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    });
     return this.newTx().add(ix);
   }
 
@@ -1,5 +1,6 @@
 import {
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   getAssociatedTokenAddressSync,
   unpackAccount,
   Account,
@@ -23,7 +24,9 @@ import {
   VeTokenSDK,
   Namespace,
   Lockup,
+  Proposal,
   VoteRecord,
+  VoteRecordV2,
   Distribution,
   DistributionClaim,
 } from "../src";
@@ -128,7 +131,8 @@ async function setupNamespace() {
 
 async function getToken(
   ctx: ProgramTestContext,
-  address: PublicKey
+  address: PublicKey,
+  programId = TOKEN_PROGRAM_ID
 ): Promise<Account | null> {
   const tokenAccount = await ctx.banksClient.getAccount(address);
   if (!tokenAccount) {
@@ -138,16 +142,19 @@ async function getToken(
     ...tokenAccount,
     data: Buffer.from(tokenAccount.data),
   };
-  const token = unpackAccount(address, tokenBuffer);
+  const token = unpackAccount(address, tokenBuffer, programId);
   return token;
 }
 
 async function getLockup(
   ctx: ProgramTestContext,
   sdk: VeTokenSDK,
-  owner: PublicKey
+  owner: PublicKey,
+  index = 0
 ): Promise<Lockup | null> {
-  const lockupAcct = await ctx.banksClient.getAccount(sdk.pdaLockup(owner));
+  const lockupAcct = await ctx.banksClient.getAccount(
+    sdk.pdaLockup(owner, index)
+  );
   if (!lockupAcct) {
     return null;
   }
@@ -159,14 +166,49 @@ async function getVoteRecord(
   sdk: VeTokenSDK,
   owner: PublicKey,
   proposal: PublicKey
-): Promise<VoteRecord | null> {
+): Promise<VoteRecordV2 | null> {
   const vr = await ctx.banksClient.getAccount(
     sdk.pdaVoteRecord(owner, proposal)
   );
   if (!vr) {
     return null;
   }
-  return VoteRecord.decode(Buffer.from(vr.data));
+  // vote writes VoteRecordV2 at the same pda as the legacy VoteRecord
+  return VoteRecordV2.decode(Buffer.from(vr.data));
+}
+
+async function getProposal(
+  ctx: ProgramTestContext,
+  proposal: PublicKey
+): Promise<Proposal | null> {
+  const proposalAcct = await ctx.banksClient.getAccount(proposal);
+  if (!proposalAcct) {
+    return null;
+  }
+  return Proposal.decode(Buffer.from(proposalAcct.data));
+}
+
+// reads a value of the receipt's token metadata, stored as borsh strings after the key
+async function getReceiptField(
+  ctx: ProgramTestContext,
+  receiptMint: PublicKey,
+  key: string
+): Promise<string | null> {
+  const mint = await ctx.banksClient.getAccount(receiptMint);
+  if (!mint) {
+    return null;
+  }
+  const data = Buffer.from(mint.data);
+  const keyBytes = Buffer.alloc(4 + key.length);
+  keyBytes.writeUInt32LE(key.length);
+  keyBytes.write(key, 4);
+  const i = data.lastIndexOf(keyBytes);
+  if (i < 0) {
+    return null;
+  }
+  const len = data.readUInt32LE(i + keyBytes.length);
+  const start = i + keyBytes.length + 4;
+  return data.subarray(start, start + len).toString();
 }
 
 async function getDistribution(
@@ -389,6 +431,15 @@ describe("pda", async () => {
         )
         .toBase58()
     ).toBe("CXuGk4xiWWwttt8q1uTEVkURXbgUTTtRHjfzT85TWrAF");
+
+    // index 0 is the original lockup, other indexes are new lockups
+    const owner = new PublicKey("EdcYCfaMXZkFv6z5tTJSiKbmJwhcwNNDCji7YNRKYfqT");
+    expect(sdk.pdaLockup(owner, 0).toBase58()).toBe(
+      "CXuGk4xiWWwttt8q1uTEVkURXbgUTTtRHjfzT85TWrAF"
+    );
+    expect(sdk.pdaLockup(owner, 1).toBase58()).not.toBe(
+      "CXuGk4xiWWwttt8q1uTEVkURXbgUTTtRHjfzT85TWrAF"
+    );
   });
 
   test("pda of proposal", async () => {
@@ -487,6 +538,29 @@ describe("stake", async () => {
       assert(!lockup.startTs.eqn(0));
     });
 
+    test("stake a second lockup at index 1 for user1", async () => {
+      const tx = sdk.txStake(
+        signers.user1.publicKey,
+        new BN(200 * 1e6),
+        endTs,
+        1
+      );
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer, signers.user1);
+      const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      assert(confirmed.result === null);
+      const lockup = await getLockup(ctx, sdk, signers.user1.publicKey, 1);
+      assert(lockup);
+      assert(lockup.amount.eq(new BN(200 * 1e6)));
+      assert(lockup.index === 1);
+      assert(lockup.owner.equals(signers.user1.publicKey));
+
+      // the original lockup is untouched
+      const original = await getLockup(ctx, sdk, signers.user1.publicKey);
+      assert(original);
+      assert(original.amount.eq(new BN(1100 * 1e6)));
+    });
+
     test("unstake can be good for user1", async () => {
       const currentClock = await ctx.banksClient.getClock();
       ctx.setClock(
@@ -528,6 +602,37 @@ describe("stake", async () => {
       expect(lockup.startTs.toNumber()).not.eq(0);
     });
 
+    test("stakeFor user2 by user1 tops up the lockup of user2", async () => {
+      const tx = sdk.txStakeFor(
+        signers.user1.publicKey,
+        signers.user2.publicKey,
+        new BN(100 * 1e6),
+        endTs.add(new BN(86400)) // ignored, the existing lockup keeps its endTs
+      );
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer, signers.user1);
+      const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      assert(confirmed.result === null);
+      const lockup = await getLockup(ctx, sdk, signers.user2.publicKey);
+      assert(lockup);
+      assert(lockup.amount.eq(new BN(500 * 1e6)));
+      assert(lockup.endTs.eq(endTs));
+      assert(lockup.owner.equals(signers.user2.publicKey));
+    });
+
+    test("stakeFor should fail below the lockup min amount", async () => {
+      const tx = sdk.txStakeFor(
+        signers.user1.publicKey,
+        signers.user2.publicKey,
+        new BN(1e6), // lockup min amount is 10 tokens
+        endTs
+      );
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer, signers.user1);
+      const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      expect(confirmed.result).contains("0x1775");
+    });
+
     test("unstake should fail because the timestamp was not there yet for user2", async () => {
       const tx = sdk.txUnstake(signers.user2.publicKey);
       tx.recentBlockhash = ctx.lastBlockhash;
@@ -561,12 +666,18 @@ describe("proposal", async () => {
         0, // nonce 0
         "https://example.com/0",
         startTs,
-        endTs
+        endTs,
+        2, // yes or no
+        ["Yes", "No"]
       );
       tx.recentBlockhash = ctx.lastBlockhash;
       tx.sign(ctx.payer, signers.reviewCouncil);
       const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
       assert(confirmed.result === null);
+      const proposal = await getProposal(ctx, sdk.pdaProposal(0));
+      assert(proposal);
+      expect(proposal.numChoices).toBe(2);
+      expect(proposal.minPassPct).toBe(60); // snapshot of the namespace
     });
 
     test("create proposal with nonce 1 by review council", async () => {
@@ -575,7 +686,9 @@ describe("proposal", async () => {
         1, // nonce 1
         "https://example.com/1",
         startTs,
-        endTs
+        endTs,
+        2, // yes or no
+        ["Yes", "No"]
       );
       tx.recentBlockhash = ctx.lastBlockhash;
       tx.sign(ctx.payer, signers.reviewCouncil);
@@ -597,13 +710,37 @@ describe("proposal", async () => {
       assert(confirmed.result === null);
     });
 
+    test("cancel proposal 1 should fail because it has started", async () => {
+      const tx = sdk.txCancelProposal(
+        signers.reviewCouncil.publicKey,
+        sdk.pdaProposal(1) // nonce 1
+      );
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer, signers.reviewCouncil);
+      const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      expect(confirmed.result).contains("0x1774");
+    });
+
+    test("veto proposal 1 by security council", async () => {
+      const tx = sdk.txVetoProposal(sdk.pdaProposal(1)); // nonce 1
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer, signers.securityCouncil);
+      const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      assert(confirmed.result === null);
+      const proposal = await getProposal(ctx, sdk.pdaProposal(1));
+      assert(proposal);
+      expect(proposal.status).toBe(6); // vetoed
+    });
+
     test("create proposal with nonce 2 with user 2 should failed because user 1 is not review council", async () => {
       const tx = sdk.txInitProposal(
         signers.user2.publicKey,
         2, // nonce 2
         "https://example.com/00",
         startTs,
-        endTs
+        endTs,
+        2, // yes or no
+        ["Yes", "No"]
       );
       tx.recentBlockhash = ctx.lastBlockhash;
       tx.sign(ctx.payer, signers.user2);
@@ -627,6 +764,18 @@ describe("proposal", async () => {
   });
 
   describe("proposal with voting", async () => {
+    test("vote should fail with a choice out of the proposal choices", async () => {
+      const tx = sdk.txVote(
+        signers.user2.publicKey,
+        sdk.pdaProposal(0),
+        2 // the proposal only has choices 0 and 1
+      );
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer, signers.user2);
+      const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      expect(confirmed.result).contains("0x1789"); // InvalidChoice
+    });
+
     test("vote by user2", async () => {
       const tx = sdk.txVote(
         signers.user2.publicKey,
@@ -647,6 +796,132 @@ describe("proposal", async () => {
       expect(vr.choice).toBe(0);
       expect(vr.votingPower.toNumber()).toBe(484094052); // TODO: this needs to be checked from the ts's voting power calculation
     });
+
+    test("change vote by user2 to choice 1", async () => {
+      const tx = sdk.txChangeVote(
+        signers.user2.publicKey,
+        sdk.pdaProposal(0),
+        1 // choice 1
+      );
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer, signers.user2);
+      const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      assert(confirmed.result === null);
+      const vr = await getVoteRecord(
+        ctx,
+        sdk,
+        signers.user2.publicKey,
+        sdk.pdaProposal(0)
+      );
+      assert(vr);
+      expect(vr.choice).toBe(1);
+      expect(vr.votingPower.toNumber()).toBe(484094052);
+    });
+
+    test("revoke vote by user2 and vote again", async () => {
+      const revokeTx = sdk.txRevokeVote(
+        signers.user2.publicKey,
+        sdk.pdaProposal(0)
+      );
+      revokeTx.recentBlockhash = ctx.lastBlockhash;
+      revokeTx.sign(ctx.payer, signers.user2);
+      let confirmed = await ctx.banksClient.tryProcessTransaction(revokeTx);
+      assert(confirmed.result === null);
+      let vr = await getVoteRecord(
+        ctx,
+        sdk,
+        signers.user2.publicKey,
+        sdk.pdaProposal(0)
+      );
+      assert(vr === null);
+
+      const voteTx = sdk.txVote(
+        signers.user2.publicKey,
+        sdk.pdaProposal(0),
+        1 // choice 1
+      );
+      voteTx.recentBlockhash = ctx.lastBlockhash;
+      voteTx.sign(ctx.payer, signers.user2);
+      confirmed = await ctx.banksClient.tryProcessTransaction(voteTx);
+      assert(confirmed.result === null);
+      vr = await getVoteRecord(
+        ctx,
+        sdk,
+        signers.user2.publicKey,
+        sdk.pdaProposal(0)
+      );
+      assert(vr);
+      expect(vr.choice).toBe(1);
+    });
+
+    test("change vote by user2 split 30/70 over choices 0 and 1", async () => {
+      let tx = sdk.txChangeVote(
+        signers.user2.publicKey,
+        sdk.pdaProposal(0),
+        0, // not the choice with the largest weight
+        0,
+        [],
+        [30, 70]
+      );
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer, signers.user2);
+      let confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      expect(confirmed.result).contains("0x177e"); // InvalidVoteRecord
+
+      tx = sdk.txChangeVote(
+        signers.user2.publicKey,
+        sdk.pdaProposal(0),
+        1,
+        0,
+        [],
+        [30, 70]
+      );
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer, signers.user2);
+      confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      assert(confirmed.result === null);
+      const vr = await getVoteRecord(
+        ctx,
+        sdk,
+        signers.user2.publicKey,
+        sdk.pdaProposal(0)
+      );
+      assert(vr);
+      expect(vr.choice).toBe(1); // the choice with the largest weight
+      expect(vr.choiceWeightsPct).toEqual([30, 70, 0, 0, 0, 0]);
+    });
+
+    test("finalize proposal should fail before it ends", async () => {
+      const tx = sdk.txFinalizeProposal(sdk.pdaProposal(0));
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(signers.user2); // any payer works, the proposal has no signer
+      const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      expect(confirmed.result).contains("0x1774");
+    });
+
+    test("finalize proposal by anyone after it ends", async () => {
+      const currentClock = await ctx.banksClient.getClock();
+      ctx.setClock(
+        new Clock(
+          currentClock.slot,
+          currentClock.epochStartTimestamp,
+          currentClock.epoch,
+          currentClock.leaderScheduleEpoch,
+          BigInt(endTs.toNumber() + 1)
+        )
+      );
+
+      const tx = sdk.txFinalizeProposal(sdk.pdaProposal(0));
+      tx.recentBlockhash = ctx.lastBlockhash;
+      tx.sign(ctx.payer);
+      const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+      assert(confirmed.result === null);
+      const proposal = await getProposal(ctx, sdk.pdaProposal(0));
+      assert(proposal);
+      expect(proposal.status).toBe(2); // passed, user2 is over the quorum
+      expect(proposal.winningChoice).toBe(1);
+      ctx.setClock(currentClock);
+    });
   });
 });
 
@@ -1412,3 +1687,267 @@ describe("Weighted Start Timestamp Corner Cases", async () => {
     }
   });
 });
+
+describe("lockup receipt", async () => {
+  const ctx = await setupCtx();
+  const signers = useSigners();
+  const sdk = new VeTokenSDK(
+    signers.deployer.publicKey,
+    signers.securityCouncil.publicKey,
+    signers.reviewCouncil.publicKey,
+    TOKEN_MINT,
+    TOKEN_PROGRAM_ID
+  );
+
+  const seller = Keypair.generate();
+  const buyer = Keypair.generate();
+  const lockup = sdk.pdaLockup(seller.publicKey);
+  const receiptMint = sdk.pdaReceiptMint(lockup);
+  let proposal: PublicKey;
+  let endTs: BN;
+
+  async function getNamespace(): Promise<Namespace> {
+    const nsAcct = await ctx.banksClient.getAccount(sdk.pdaNamespace());
+    assert(nsAcct);
+    return Namespace.decode(Buffer.from(nsAcct.data));
+  }
+
+  async function setUnstakeCooldown(cooldown: BN) {
+    const ns = await getNamespace();
+    const tx = sdk.txUpdateNamespace(
+      ns.securityCouncil,
+      ns.reviewCouncil,
+      ns.lockupDefaultTargetRewardsPct,
+      ns.lockupDefaultTargetVotingPct,
+      ns.lockupMinDuration,
+      ns.lockupMinAmount,
+      ns.lockupMaxSaturation,
+      ns.proposalMinVotingPowerForQuorum,
+      ns.proposalMinPassPct,
+      ns.proposalCanUpdateAfterVotes,
+      ns.lockupEarlyUnlockPenaltyPct,
+      ns.lockupVotingPowerMode,
+      cooldown,
+      ns.lockupVotingCurve.slice(0, ns.lockupVotingCurveLen)
+    );
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, signers.securityCouncil);
+    const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+  }
+
+  async function warpTo(unixTimestamp: bigint) {
+    const clock = await ctx.banksClient.getClock();
+    ctx.setClock(
+      new Clock(
+        clock.slot + 100n,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        unixTimestamp
+      )
+    );
+  }
+
+  test("mint a receipt for the lockup of the seller", async () => {
+    await airdrop(ctx, seller.publicKey, 1 * LAMPORTS_PER_SOL);
+    await airdrop(ctx, buyer.publicKey, 1 * LAMPORTS_PER_SOL);
+    await transferToken(ctx, TOKEN_MINT, signers.user1, seller.publicKey, 1000 * 1e6);
+
+    const clock = await ctx.banksClient.getClock();
+    endTs = new BN(Number(clock.unixTimestamp) + 86400 * 30); // 30 days
+    let tx = sdk.txStake(seller.publicKey, new BN(500 * 1e6), endTs);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, seller);
+    let confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+
+    tx = sdk.txMintLockupReceipt(seller.publicKey);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, seller);
+    confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+
+    const l = await getLockup(ctx, sdk, seller.publicKey);
+    assert(l);
+    assert(l.receiptMint.equals(receiptMint));
+    const receipt = await getToken(
+      ctx,
+      sdk.receiptAta(seller.publicKey, lockup),
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert(receipt);
+    expect(receipt.amount).toBe(1n);
+    expect(await getReceiptField(ctx, receiptMint, "amount")).toBe(`${500 * 1e6}`);
+    expect(await getReceiptField(ctx, receiptMint, "end_ts")).toBe(endTs.toString());
+  });
+
+  test("transfer the receipt to the buyer", async () => {
+    const buyerReceipt = sdk.receiptAta(buyer.publicKey, lockup);
+    const tx = new Transaction().add(
+      createAssociatedTokenAccountIdempotentInstruction(
+        seller.publicKey,
+        buyerReceipt,
+        buyer.publicKey,
+        receiptMint,
+        TOKEN_2022_PROGRAM_ID
+      ),
+      createTransferCheckedInstruction(
+        sdk.receiptAta(seller.publicKey, lockup),
+        receiptMint,
+        buyerReceipt,
+        seller.publicKey,
+        1,
+        0,
+        [],
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, seller);
+    const confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+
+    const receipt = await getToken(ctx, buyerReceipt, TOKEN_2022_PROGRAM_ID);
+    assert(receipt);
+    expect(receipt.amount).toBe(1n);
+  });
+
+  test("the buyer votes with the receipt and the seller can't vote anymore", async () => {
+    const ns = await getNamespace();
+    const clock = await ctx.banksClient.getClock();
+    const now = Number(clock.unixTimestamp);
+    proposal = sdk.pdaProposal(ns.proposalNonce);
+    let tx = sdk.txInitProposal(
+      signers.reviewCouncil.publicKey,
+      ns.proposalNonce,
+      "https://example.com/receipt",
+      new BN(now), // votes count the voting power as of the proposal start
+      new BN(now + 86400 * 3), // 3 days of proposal duration
+      2 // yes or no
+    );
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, signers.reviewCouncil);
+    let confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+
+    // the owner-signed vote is rejected once the lockup has a receipt
+    tx = sdk.txVote(seller.publicKey, proposal, 0);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, seller);
+    confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).contains("0x1787"); // LockupHasReceipt
+
+    // and the seller doesn't hold the receipt anymore
+    tx = sdk.txVoteWithReceipt(seller.publicKey, lockup, proposal, 0);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, seller);
+    confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).contains("0x1788"); // InvalidReceipt
+
+    tx = sdk.txVoteWithReceipt(buyer.publicKey, lockup, proposal, 0);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, buyer);
+    confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+
+    const vr = await ctx.banksClient.getAccount(
+      sdk.pdaReceiptVoteRecord(lockup, proposal)
+    );
+    assert(vr);
+    const voteRecord = VoteRecordV2.decode(Buffer.from(vr.data));
+    assert(voteRecord.owner.equals(buyer.publicKey));
+    assert(voteRecord.lockup.equals(lockup));
+    expect(voteRecord.choice).toBe(0);
+    expect(voteRecord.votingPower.toNumber()).toBeGreaterThan(0);
+  });
+
+  test("refresh the receipt after stakeTo", async () => {
+    let tx = sdk.txStakeTo(seller.publicKey, new BN(100 * 1e6), endTs);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, signers.securityCouncil);
+    let confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+    expect(await getReceiptField(ctx, receiptMint, "amount")).toBe(`${500 * 1e6}`);
+
+    // anyone can refresh the metadata
+    tx = sdk.txRefreshLockupReceipt(buyer.publicKey, lockup);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, buyer);
+    confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+    expect(await getReceiptField(ctx, receiptMint, "amount")).toBe(`${600 * 1e6}`);
+    expect(await getReceiptField(ctx, receiptMint, "end_ts")).toBe(endTs.toString());
+  });
+
+  test("the buyer requests the unstake and unstakes with the receipt", async () => {
+    const currentClock = await ctx.banksClient.getClock();
+    await setUnstakeCooldown(new BN(86400)); // request_unstake needs a cooldown
+    await warpTo(BigInt(endTs.toNumber()) + 1n);
+
+    let tx = sdk.txRequestUnstakeWithReceipt(buyer.publicKey, lockup);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, buyer);
+    let confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+    const l = await getLockup(ctx, sdk, seller.publicKey);
+    assert(l);
+    expect(l.unstakeRequestedTs.toNumber()).toBe(endTs.toNumber() + 1);
+
+    tx = sdk.txUnstakeWithReceipt(buyer.publicKey, seller.publicKey);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, buyer);
+    confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).contains("0x1783"); // UnstakeCooldownNotEnded
+
+    await warpTo(BigInt(endTs.toNumber()) + 1n + 86400n);
+    tx = sdk.txUnstakeWithReceipt(buyer.publicKey, seller.publicKey);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(buyer); // paying the fees makes it a different transaction than the failed one
+    confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+
+    // the tokens go to the buyer, the receipt is burned and its mint closed
+    const buyerToken = await getToken(ctx, sdk.ata(buyer.publicKey));
+    assert(buyerToken);
+    expect(buyerToken.amount).toBe(BigInt(600 * 1e6));
+    assert((await getLockup(ctx, sdk, seller.publicKey)) === null);
+    assert((await ctx.banksClient.getAccount(receiptMint)) === null);
+    assert(
+      (await ctx.banksClient.getAccount(sdk.receiptAta(buyer.publicKey, lockup))) ===
+        null
+    );
+
+    ctx.setClock(currentClock);
+    await setUnstakeCooldown(new BN(0));
+  });
+
+  test("the lockup can be staked again and get a new receipt", async () => {
+    const clock = await ctx.banksClient.getClock();
+    endTs = new BN(Number(clock.unixTimestamp) + 86400 * 60); // 60 days
+    let tx = sdk.txStake(seller.publicKey, new BN(300 * 1e6), endTs);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(ctx.payer, seller);
+    let confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+
+    // the same mint address, the seller's empty receipt token account is reused
+    tx = sdk.txMintLockupReceipt(seller.publicKey);
+    tx.recentBlockhash = ctx.lastBlockhash;
+    tx.sign(seller); // paying the fees makes it a different transaction than the first mint
+    confirmed = await ctx.banksClient.tryProcessTransaction(tx);
+    expect(confirmed.result).toBe(null);
+
+    const l = await getLockup(ctx, sdk, seller.publicKey);
+    assert(l);
+    assert(l.receiptMint.equals(receiptMint));
+    const receipt = await getToken(
+      ctx,
+      sdk.receiptAta(seller.publicKey, lockup),
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert(receipt);
+    expect(receipt.amount).toBe(1n);
+    expect(await getReceiptField(ctx, receiptMint, "amount")).toBe(`${300 * 1e6}`);
+  });
+});